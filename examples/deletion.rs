@@ -2,7 +2,7 @@ use redis_vector_store::{
     RedisConfig, PointStruct, Payload, Metadata,
     create_collection, get_collection,
     add_vector_and_metadata, get_vector,
-    delete_vector_and_metadata, delete_collection,
+    delete_vector_and_metadata, delete_collection, plan_delete_collection,
 };
 
 #[tokio::main]
@@ -56,6 +56,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     println!("\n--- Deleting entire collection ---");
+    let plan = plan_delete_collection(&redis_config, collection_name).await?;
+    println!("Dry run: {} keys would be deleted", plan.count);
     delete_collection(&redis_config, collection_name).await?;
     println!("Collection deleted successfully");
 
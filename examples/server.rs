@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use redis_vector_store::{CollectionConfig, RedisConfig, get_embedding_driver, server};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    env_logger::init();
+
+    let redis_config = RedisConfig::from_env();
+    let embedding_driver = Arc::new(get_embedding_driver(
+        "models/text-embedding-004",
+        std::env::var("GOOGLE_API_KEY").ok().as_deref()
+    ));
+
+    // Serve only the collections listed in COLLECTIONS (comma-separated)
+    let collections: HashMap<String, CollectionConfig> = std::env::var("COLLECTIONS")
+        .unwrap_or_else(|_| "my_collection".to_string())
+        .split(',')
+        .map(|name| (name.trim().to_string(), CollectionConfig::new()))
+        .collect();
+
+    let addr = std::env::var("BIND_ADDR").unwrap_or_else(|_| "127.0.0.1:8080".to_string());
+    let listener = tokio::net::TcpListener::bind(&addr).await?;
+    println!("Serving on http://{}", addr);
+    println!("  POST   /collections/{{name}}/vectors");
+    println!("  POST   /collections/{{name}}/search");
+    println!("  GET    /vectors/{{collection}}:{{id}}");
+    println!("  DELETE /vectors/{{collection}}:{{id}}");
+
+    axum::serve(listener, server::router(redis_config, embedding_driver, collections)).await?;
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::redis_vector_store_driver::EmbeddingDriver;
+use crate::error::{check_status, VectorStoreError};
+
+/// Cohere embedding driver.
+///
+/// POSTs to Cohere's `/v1/embed` endpoint, sending many texts per request from
+/// [`embed_strings`](EmbeddingDriver::embed_strings).
+pub struct CohereEmbeddingDriver {
+    model: String,
+    api_key: String,
+    input_type: Option<InputType>,
+    base_url: String,
+    client: reqwest::Client,
+}
+
+const DEFAULT_BASE_URL: &str = "https://api.cohere.ai";
+
+/// Most texts Cohere accepts in one embed request; larger batches are split.
+pub const COHERE_MAX_BATCH: usize = 96;
+
+/// What an embedding will be used for, sent as the API's `input_type`.
+///
+/// Cohere's v3 models embed queries and documents differently; the store's driver picks
+/// `SearchQuery`/`SearchDocument` through [`embed_query`](EmbeddingDriver::embed_query) and
+/// [`embed_document`](EmbeddingDriver::embed_document).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InputType {
+    SearchQuery,
+    SearchDocument,
+    Classification,
+    Clustering,
+}
+
+#[derive(Serialize)]
+struct EmbedRequest<'a> {
+    model: &'a str,
+    texts: &'a [String],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_type: Option<InputType>,
+}
+
+#[derive(Deserialize)]
+struct EmbedResponse {
+    embeddings: Vec<Vec<f64>>,
+}
+
+impl CohereEmbeddingDriver {
+    /// Create a new Cohere Embedding Driver, e.g. for `"embed-multilingual-v3.0"`.
+    pub fn new(model: &str, api_key: &str) -> Self {
+        Self {
+            model: model.to_string(),
+            api_key: api_key.to_string(),
+            input_type: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Set the input type used by [`embed_string`](EmbeddingDriver::embed_string) and
+    /// [`embed_strings`](EmbeddingDriver::embed_strings) (default: [`InputType::SearchDocument`],
+    /// as plain embeddings are usually of documents to store).
+    pub fn with_input_type(mut self, input_type: InputType) -> Self {
+        self.input_type = Some(input_type);
+        self
+    }
+
+    /// Send requests to `base_url` instead of `https://api.cohere.ai`.
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Use a pre-built HTTP client instead of the default one, e.g. to configure proxies or timeouts.
+    pub fn with_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    pub fn input_type(&self) -> Option<InputType> {
+        self.input_type
+    }
+
+    async fn embed(&self, texts: &[String], input_type: Option<InputType>) -> Result<Vec<Vec<f64>>, VectorStoreError> {
+        let mut vectors = Vec::with_capacity(texts.len());
+        for batch in texts.chunks(COHERE_MAX_BATCH) {
+            vectors.extend(self.embed_batch(batch, input_type).await?);
+        }
+        Ok(vectors)
+    }
+
+    async fn embed_batch(&self, texts: &[String], input_type: Option<InputType>) -> Result<Vec<Vec<f64>>, VectorStoreError> {
+        let url = format!("{}/v1/embed", self.base_url);
+
+        let response = self.client
+            .post(&url)
+            .bearer_auth(&self.api_key)
+            .json(&EmbedRequest { model: &self.model, texts, input_type })
+            .send()
+            .await?;
+
+        let response = check_status(response, "Cohere API").await?;
+
+        let embed_response: EmbedResponse = response
+            .json()
+            .await?;
+
+        if embed_response.embeddings.len() != texts.len() {
+            return Err(VectorStoreError::Other(format!(
+                "API returned {} embeddings for {} texts",
+                embed_response.embeddings.len(), texts.len()
+            )));
+        }
+        Ok(embed_response.embeddings)
+    }
+
+    /// The input type of plain embeddings: the configured one, else `SearchDocument`.
+    fn default_input_type(&self) -> Option<InputType> {
+        Some(self.input_type.unwrap_or(InputType::SearchDocument))
+    }
+
+    async fn embed_one(&self, text: &str, input_type: Option<InputType>) -> Result<Vec<f64>, VectorStoreError> {
+        self.embed_batch(&[text.to_string()], input_type).await?
+            .pop()
+            .ok_or_else(|| VectorStoreError::Other("API response missing embedding".to_string()))
+    }
+}
+
+#[async_trait]
+impl EmbeddingDriver for CohereEmbeddingDriver {
+    async fn embed_string(&self, text: &str) -> Result<Vec<f64>, VectorStoreError> {
+        self.embed_one(text, self.default_input_type()).await
+    }
+
+    async fn embed_query(&self, text: &str) -> Result<Vec<f64>, VectorStoreError> {
+        self.embed_one(text, Some(InputType::SearchQuery)).await
+    }
+
+    async fn embed_document(&self, text: &str) -> Result<Vec<f64>, VectorStoreError> {
+        self.embed_one(text, Some(InputType::SearchDocument)).await
+    }
+
+    async fn embed_strings(&self, texts: &[String]) -> Result<Vec<Vec<f64>>, VectorStoreError> {
+        self.embed(texts, self.default_input_type()).await
+    }
+}
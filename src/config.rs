@@ -1,19 +1,53 @@
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS, NON_ALPHANUMERIC};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::env;
+use std::future::Future;
+use std::time::Duration;
+
+use crate::error::VectorStoreError;
+use crate::redis_engine::DEFAULT_VECTOR_DIM;
+
+/// Default RediSearch query dialect; the first one supporting vector queries.
+pub const DEFAULT_DIALECT: u32 = 2;
+
+/// Characters escaped in the path of a `redis+unix://` URL; `/` separators are kept.
+const URL_PATH: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'#').add(b'%').add(b'<').add(b'>').add(b'?').add(b'`').add(b'{').add(b'}');
 
 /// Redis connection configuration.
 ///
 /// Construct via `RedisConfig::from_env()` for the standard workflow,
 /// or `RedisConfig::new()` for programmatic setup.
-#[derive(Clone, Debug)]
+///
+/// Also deserializable from config files: every field is optional (`localhost:6379`, no
+/// password by default) and `url` is rebuilt from the parts rather than read.
+///
+/// ```
+/// use redis_vector_store::RedisConfig;
+///
+/// let config: RedisConfig = serde_json::from_str(r#"{"hostname": "redis.internal", "port": 6380}"#).unwrap();
+/// assert_eq!(config.url, "redis://redis.internal:6380");
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(from = "RedisConfigFields")]
 pub struct RedisConfig {
     /// Full Redis connection URL (e.g. `redis://localhost:6379`).
+    #[serde(skip)]
     pub url: String,
-    /// Redis hostname.
+    /// Redis hostname; unused when `unix_socket` is set.
     pub hostname: String,
-    /// Redis port.
+    /// Redis port; unused when `unix_socket` is set.
     pub port: u16,
+    /// Path of the Unix domain socket to connect through instead of TCP.
+    pub unix_socket: Option<String>,
     /// Optional password for AUTH.
     pub password: Option<String>,
+    /// How transient command failures are retried (default: [`RetryPolicy::default`]).
+    pub retry_policy: RetryPolicy,
+    /// Connect to a Redis Cluster, using `url` as the seed node (default: `false`).
+    pub cluster: bool,
+    /// RediSearch query dialect sent with every `FT.SEARCH` and `FT.AGGREGATE` (default: `2`).
+    pub dialect: u32,
 }
 
 impl RedisConfig {
@@ -28,23 +62,86 @@ impl RedisConfig {
             url,
             hostname: hostname.to_string(),
             port,
+            unix_socket: None,
             password: password.map(String::from),
+            retry_policy: RetryPolicy::default(),
+            cluster: false,
+            dialect: DEFAULT_DIALECT,
+        }
+    }
+
+    /// Connect through the Unix domain socket at `path` (absolute), e.g. `/run/redis/redis.sock`.
+    ///
+    /// Produces a `redis+unix:///path` URL, passing the password as the `pass` query parameter.
+    /// Both are percent-encoded. Unix sockets are only available on Unix platforms.
+    pub fn from_unix_socket(path: &str, password: Option<&str>) -> Self {
+        let encoded_path = utf8_percent_encode(path, URL_PATH);
+        let url = match &password {
+            Some(pass) => format!("redis+unix://{}?pass={}", encoded_path, utf8_percent_encode(pass, NON_ALPHANUMERIC)),
+            None => format!("redis+unix://{}", encoded_path),
+        };
+
+        RedisConfig {
+            url,
+            unix_socket: Some(path.to_string()),
+            ..Self::new("localhost", 6379, password)
         }
     }
 
+    /// Treat `url` as the seed node of a Redis Cluster and follow `MOVED`/`ASK` redirects.
+    ///
+    /// Search commands need RediSearch with cluster support (Redis Enterprise, or Redis 8 /
+    /// RediSearch coordinator builds). Pipelines are split into single commands because a
+    /// batch spans many hash slots, so batch writes cost one round-trip per command.
+    /// `SCAN`-based operations (`scan_ids`, `clear`, `export_entries`, `scan_entries`) only see
+    /// one node and are not supported on a cluster.
+    pub fn with_cluster(mut self, cluster: bool) -> Self {
+        self.cluster = cluster;
+        self
+    }
+
+    /// Set the RediSearch query dialect (default: `2`).
+    ///
+    /// KNN and vector range queries need dialect 2 or higher; lower it only for servers that
+    /// reject `DIALECT 2`.
+    pub fn with_dialect(mut self, dialect: u32) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Set how transient command failures are retried; `RetryPolicy::none()` disables retries.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
     /// Load configuration from environment variables:
     /// - `REDIS_HOSTNAME` (default: `localhost`)
     /// - `REDIS_PORT` (default: `6379`)
     /// - `REDIS_PASSWORD` (optional)
+    /// - `REDIS_CLUSTER` (`1` or `true` to connect in cluster mode)
+    /// - `REDIS_SOCKET` (optional Unix socket path; overrides hostname and port)
     pub fn from_env() -> Self {
-        let hostname = env::var("REDIS_HOSTNAME").unwrap_or_else(|_| "localhost".to_string());
-        let port = env::var("REDIS_PORT")
-            .unwrap_or_else(|_| "6379".to_string())
+        Self::from_lookup(|name| env::var(name).ok())
+    }
+
+    /// Like [`from_env`](Self::from_env), reading the variables through `lookup`, e.g. from a
+    /// map in tests.
+    pub fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Self {
+        let hostname = lookup("REDIS_HOSTNAME").unwrap_or_else(|| "localhost".to_string());
+        let port = lookup("REDIS_PORT")
+            .unwrap_or_else(|| "6379".to_string())
             .parse::<u16>()
             .unwrap_or(6379);
-        let password = env::var("REDIS_PASSWORD").ok();
+        let password = lookup("REDIS_PASSWORD");
 
-        Self::new(&hostname, port, password.as_deref())
+        let cluster = lookup("REDIS_CLUSTER").is_some_and(|v| v == "1" || v.eq_ignore_ascii_case("true"));
+
+        let config = match lookup("REDIS_SOCKET") {
+            Some(path) => Self::from_unix_socket(&path, password.as_deref()),
+            None => Self::new(&hostname, port, password.as_deref()),
+        };
+        config.with_cluster(cluster)
     }
 
     /// Get the Redis connection URL.
@@ -52,3 +149,484 @@ impl RedisConfig {
         &self.url
     }
 }
+
+/// The serialized form of [`RedisConfig`], without the derived `url`.
+#[derive(Deserialize)]
+#[serde(default)]
+struct RedisConfigFields {
+    hostname: String,
+    port: u16,
+    unix_socket: Option<String>,
+    password: Option<String>,
+    retry_policy: RetryPolicy,
+    cluster: bool,
+    dialect: u32,
+}
+
+impl Default for RedisConfigFields {
+    fn default() -> Self {
+        Self {
+            hostname: "localhost".to_string(),
+            port: 6379,
+            unix_socket: None,
+            password: None,
+            retry_policy: RetryPolicy::default(),
+            cluster: false,
+            dialect: DEFAULT_DIALECT,
+        }
+    }
+}
+
+impl From<RedisConfigFields> for RedisConfig {
+    fn from(fields: RedisConfigFields) -> Self {
+        let base = match &fields.unix_socket {
+            Some(path) => RedisConfig::from_unix_socket(path, fields.password.as_deref()),
+            None => RedisConfig::new(&fields.hostname, fields.port, fields.password.as_deref()),
+        };
+        RedisConfig {
+            hostname: fields.hostname,
+            port: fields.port,
+            retry_policy: fields.retry_policy,
+            cluster: fields.cluster,
+            dialect: fields.dialect,
+            ..base
+        }
+    }
+}
+
+/// Exponential backoff for transient Redis failures.
+///
+/// Commands failing with I/O errors (dropped connections, timeouts) or with `LOADING`,
+/// `TRYAGAIN`, `CLUSTERDOWN` or `MASTERDOWN` are retried up to `max_retries` times, waiting
+/// `base_delay * 2^attempt` (capped at `max_delay`) in between. Other errors, such as
+/// `WRONGTYPE` or syntax errors, are returned immediately.
+///
+/// A pipeline is retried as a whole, so commands that ran before a dropped connection may
+/// run twice. That is harmless for overwrites such as `HSET` and `JSON.SET`, but not for
+/// pipelines whose replies are used or that move keys, so the existence check of
+/// [`upsert_point`](crate::RedisEngine::upsert_point), the deletion counts of
+/// [`clear`](crate::RedisEngine::clear) and the `RENAME`s of
+/// [`reindex`](crate::RedisEngine::reindex) are sent only once.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt; `0` disables retrying.
+    pub max_retries: u32,
+    /// Delay before the first retry.
+    pub base_delay: Duration,
+    /// Upper bound for any single delay.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    /// Three retries, starting at 50 ms and capped at 2 s.
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(50),
+            max_delay: Duration::from_secs(2),
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new(max_retries: u32, base_delay: Duration, max_delay: Duration) -> Self {
+        Self { max_retries, base_delay, max_delay }
+    }
+
+    /// Never retry.
+    pub fn none() -> Self {
+        Self { max_retries: 0, ..Self::default() }
+    }
+
+    /// Delay before retry number `attempt` (starting at 0).
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay)
+    }
+
+    /// Whether `err` is worth retrying: connection-level failures and server states that clear up on their own.
+    pub fn is_transient(err: &redis::RedisError) -> bool {
+        use redis::{ErrorKind, ServerErrorKind};
+        err.is_io_error()
+            || err.is_timeout()
+            || err.is_connection_dropped()
+            || matches!(
+                err.kind(),
+                ErrorKind::Server(
+                    ServerErrorKind::BusyLoading
+                        | ServerErrorKind::TryAgain
+                        | ServerErrorKind::ClusterDown
+                        | ServerErrorKind::MasterDown
+                )
+            )
+    }
+
+    /// Run `op`, retrying transient failures with backoff.
+    pub async fn run<T, F, Fut>(&self, mut op: F) -> redis::RedisResult<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = redis::RedisResult<T>>,
+    {
+        let mut attempt = 0;
+        loop {
+            match op().await {
+                Err(err) if attempt < self.max_retries && Self::is_transient(&err) => {
+                    let delay = self.delay_for(attempt);
+                    log::warn!("Transient Redis error (retry {} in {:?}): {}", attempt + 1, delay, err);
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Where a collection keeps its points.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StorageMode {
+    /// `ON HASH` index: the vector lives in a hash at `{collection}:{id}` and the payload in a
+    /// separate RedisJSON document at `metadata:{id}`.
+    #[default]
+    Hash,
+    /// `ON JSON` index: vector (`$.vector`), payload (`$.payload`) and bookkeeping fields share
+    /// one RedisJSON document at `{collection}:{id}`. Reads and deletes touch a single key.
+    Json,
+}
+
+/// Index-level settings for a collection, used when creating it and when reading or writing it.
+///
+/// Every engine or driver that touches a collection must use the same settings it was created with.
+///
+/// ```
+/// use redis_vector_store::{CollectionConfig, StorageMode};
+///
+/// let config = CollectionConfig::new().vector_dim(384).storage(StorageMode::Json);
+/// assert_eq!(config.vector_dim, 384);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CollectionConfig {
+    /// Vector dimension (default [`DEFAULT_VECTOR_DIM`]).
+    pub vector_dim: usize,
+    /// Storage layout (default [`StorageMode::Hash`]).
+    pub storage: StorageMode,
+    /// Distance metric of the vector fields (default [`DistanceMetric::Cosine`]).
+    pub distance_metric: DistanceMetric,
+    /// Vector index algorithm (default [`VectorAlgorithm::Flat`]).
+    pub algorithm: VectorAlgorithm,
+    /// `INITIAL_CAP`: vectors to preallocate index memory for (default: RediSearch's).
+    pub initial_cap: Option<usize>,
+    /// FLAT `BLOCK_SIZE`: vectors per allocation when the index grows (default: RediSearch's, 1024).
+    pub block_size: Option<usize>,
+    /// Index a `deleted` tag so points can be tombstoned instead of removed (default: `false`).
+    pub soft_delete: bool,
+    /// Write the legacy `{collection}:empty` zero vector when creating the collection (default: `false`).
+    pub create_placeholder: bool,
+    /// Named vector fields indexed next to `vector`, with the same dimension (default: none).
+    pub vector_fields: Vec<String>,
+    /// Index the payload's `content` as a full-text `TEXT` field (default: `false`).
+    pub index_content: bool,
+}
+
+impl Default for CollectionConfig {
+    fn default() -> Self {
+        Self {
+            vector_dim: DEFAULT_VECTOR_DIM,
+            storage: StorageMode::default(),
+            distance_metric: DistanceMetric::default(),
+            algorithm: VectorAlgorithm::default(),
+            initial_cap: None,
+            block_size: None,
+            soft_delete: false,
+            create_placeholder: false,
+            vector_fields: Vec::new(),
+            index_content: false,
+        }
+    }
+}
+
+impl CollectionConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn vector_dim(mut self, vector_dim: usize) -> Self {
+        self.vector_dim = vector_dim;
+        self
+    }
+
+    pub fn storage(mut self, storage: StorageMode) -> Self {
+        self.storage = storage;
+        self
+    }
+
+    /// Measure vector distances with `distance_metric`. Only applies when the collection is created.
+    pub fn distance_metric(mut self, distance_metric: DistanceMetric) -> Self {
+        self.distance_metric = distance_metric;
+        self
+    }
+
+    /// Index vectors with `algorithm`. Only applies when the collection is created.
+    pub fn algorithm(mut self, algorithm: VectorAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Preallocate index memory for `initial_cap` vectors; set it near the expected size
+    /// before a bulk load. Only applies when the collection is created.
+    pub fn initial_cap(mut self, initial_cap: usize) -> Self {
+        self.initial_cap = Some(initial_cap);
+        self
+    }
+
+    /// Grow a FLAT index `block_size` vectors at a time; HNSW indexes ignore it. Only applies
+    /// when the collection is created.
+    pub fn block_size(mut self, block_size: usize) -> Self {
+        self.block_size = Some(block_size);
+        self
+    }
+
+    /// Add a `deleted` TAG field to the schema so points can be soft-deleted
+    /// (see [`RedisEngine::soft_delete`](crate::RedisEngine::soft_delete)), and exclude
+    /// tombstoned points from searches and counts. Only applies when the collection is created.
+    pub fn soft_delete(mut self, soft_delete: bool) -> Self {
+        self.soft_delete = soft_delete;
+        self
+    }
+
+    /// Write a zero vector `{collection}:empty` (with a `metadata:{collection}:empty` payload
+    /// for hash collections) when the collection is created, as older releases always did.
+    /// Only for deployments whose tooling expects the key. The index leaves it out (with an
+    /// `FT.CREATE` `FILTER`), so it never appears in searches or counts.
+    pub fn create_placeholder(mut self, create_placeholder: bool) -> Self {
+        self.create_placeholder = create_placeholder;
+        self
+    }
+
+    /// Add a `content` TEXT field holding each point's payload text, for hybrid full-text +
+    /// vector queries (see [`RedisStackVectorStoreDriver::hybrid_query`](crate::redis_vector_store_driver::RedisStackVectorStoreDriver::hybrid_query)).
+    /// Hash collections then keep a copy of the content in each vector hash. Only applies when
+    /// the collection is created.
+    pub fn index_content(mut self, index_content: bool) -> Self {
+        self.index_content = index_content;
+        self
+    }
+
+    /// Index an extra vector field `name` (e.g. `title_vec`) alongside the default `vector`,
+    /// for documents with several embeddings. Points fill it through
+    /// [`PointStruct::named_vectors`](crate::GenericPointStruct::named_vectors) and queries target it with
+    /// [`QueryOptions::vector_field`](crate::QueryOptions::vector_field). Only applies when the
+    /// collection is created.
+    pub fn vector_field(mut self, name: &str) -> Self {
+        if name != "vector" && !self.vector_fields.iter().any(|field| field == name) {
+            self.vector_fields.push(name.to_string());
+        }
+        self
+    }
+}
+
+/// How to handle vectors whose length differs from the collection dimension.
+///
+/// Padding and truncation keep ingestion going when models are mixed, but they change
+/// the vector's geometry: truncated vectors lose whatever signal lived in the dropped
+/// components, and zero-padded vectors only compare meaningfully against other vectors
+/// from the same (shorter) model. Expect degraded recall for coerced vectors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DimensionPolicy {
+    /// Reject any vector whose length differs from the collection dimension.
+    #[default]
+    Strict,
+    /// Drop trailing components of longer vectors; shorter vectors are rejected.
+    Truncate,
+    /// Zero-pad shorter vectors; longer vectors are rejected.
+    Pad,
+    /// Zero-pad shorter vectors and truncate longer ones, with a logged warning
+    /// (see [`pad_or_truncate`](crate::pad_or_truncate)).
+    PadOrTruncate,
+    /// Leave mismatched points out of upserts, with a logged warning, and write the rest.
+    /// Single-point upserts report the skip ([`UpsertOutcome::skipped`](crate::UpsertOutcome::skipped),
+    /// or an error from [`RedisEngine::upsert_point`](crate::RedisEngine::upsert_point)).
+    /// [`apply`](Self::apply) reports the mismatch as an error, like [`Strict`](Self::Strict).
+    Skip,
+}
+
+impl DimensionPolicy {
+    /// Conform `vector` to `dim` according to this policy.
+    /// Borrows when no change is needed.
+    pub fn apply<'a>(&self, vector: &'a [f64], dim: usize) -> Result<Cow<'a, [f64]>, VectorStoreError> {
+        match (*self, vector.len().cmp(&dim)) {
+            (_, std::cmp::Ordering::Equal) => Ok(Cow::Borrowed(vector)),
+            (DimensionPolicy::Truncate, std::cmp::Ordering::Greater) => Ok(Cow::Borrowed(&vector[..dim])),
+            (DimensionPolicy::Pad, std::cmp::Ordering::Less) => {
+                let mut padded = vector.to_vec();
+                padded.resize(dim, 0.0);
+                Ok(Cow::Owned(padded))
+            }
+            (DimensionPolicy::PadOrTruncate, _) => Ok(Cow::Owned(crate::distance::pad_or_truncate(vector.to_vec(), dim))),
+            _ => Err(VectorStoreError::Other(format!(
+                "Vector dimension mismatch: expected {}, got {}",
+                dim,
+                vector.len()
+            ))),
+        }
+    }
+}
+
+/// What to do with content longer than a driver's
+/// [`max_content_len`](crate::redis_vector_store_driver::RedisStackVectorStoreDriver::with_max_content_len).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ContentLengthPolicy {
+    /// Cut the content down to the limit, with a logged warning.
+    #[default]
+    Truncate,
+    /// Reject the upsert.
+    Reject,
+}
+
+impl ContentLengthPolicy {
+    /// Limit `content` to `max_len` bytes according to this policy, truncating at a character
+    /// boundary. Returns the original length when the content was truncated.
+    pub fn apply(&self, content: &mut String, max_len: usize) -> Result<Option<usize>, VectorStoreError> {
+        let len = content.len();
+        if len <= max_len {
+            return Ok(None);
+        }
+        match self {
+            ContentLengthPolicy::Truncate => {
+                let end = (0..=max_len).rev().find(|&i| content.is_char_boundary(i)).unwrap_or(0);
+                content.truncate(end);
+                log::warn!("Truncated content from {} to {} bytes", len, end);
+                Ok(Some(len))
+            }
+            ContentLengthPolicy::Reject => Err(VectorStoreError::Other(format!(
+                "Content too long: {} bytes, limit {}",
+                len, max_len
+            ))),
+        }
+    }
+}
+
+/// Distance metric of a collection's vector index.
+///
+/// RediSearch reports every metric as a distance (lower = closer);
+/// [`similarity`](Self::similarity) converts it to a higher-is-better score.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DistanceMetric {
+    /// Cosine distance, `1 - cos(a, b)`, in `0..=2`.
+    #[default]
+    Cosine,
+    /// Squared Euclidean distance.
+    L2,
+    /// Inner-product distance, `1 - a·b`.
+    Ip,
+}
+
+impl DistanceMetric {
+    /// The `DISTANCE_METRIC` argument for `FT.CREATE`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DistanceMetric::Cosine => "COSINE",
+            DistanceMetric::L2 => "L2",
+            DistanceMetric::Ip => "IP",
+        }
+    }
+
+    /// Parse a `DISTANCE_METRIC` as `FT.INFO` reports it, ignoring case.
+    pub fn parse(metric: &str) -> Option<Self> {
+        [DistanceMetric::Cosine, DistanceMetric::L2, DistanceMetric::Ip]
+            .into_iter()
+            .find(|candidate| candidate.as_str().eq_ignore_ascii_case(metric))
+    }
+
+    /// Convert a raw distance from this metric into a similarity where higher is better.
+    ///
+    /// - `Cosine`: `1 - distance`, i.e. the cosine similarity (1 = same direction, 0 = orthogonal).
+    /// - `Ip`: `1 - distance`, i.e. the inner product (equal to cosine for unit vectors).
+    /// - `L2`: `1 / (1 + distance)`, mapping `0..∞` onto `1..0`.
+    pub fn similarity(&self, distance: f64) -> f64 {
+        match self {
+            DistanceMetric::Cosine | DistanceMetric::Ip => 1.0 - distance,
+            DistanceMetric::L2 => 1.0 / (1.0 + distance),
+        }
+    }
+}
+
+/// Algorithm of a collection's vector index.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VectorAlgorithm {
+    /// Exact brute-force search; best below a few hundred thousand vectors.
+    #[default]
+    Flat,
+    /// Approximate HNSW graph search; faster on large collections, at some recall cost.
+    Hnsw,
+}
+
+impl VectorAlgorithm {
+    /// The algorithm argument for `FT.CREATE`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VectorAlgorithm::Flat => "FLAT",
+            VectorAlgorithm::Hnsw => "HNSW",
+        }
+    }
+
+    /// Parse an algorithm as `FT.INFO` reports it, ignoring case.
+    pub fn parse(algorithm: &str) -> Option<Self> {
+        [VectorAlgorithm::Flat, VectorAlgorithm::Hnsw]
+            .into_iter()
+            .find(|candidate| candidate.as_str().eq_ignore_ascii_case(algorithm))
+    }
+}
+
+/// Type of a secondary index field added with
+/// [`RedisEngine::add_index_field`](crate::RedisEngine::add_index_field).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FieldType {
+    /// Exact-match values, filtered with `@field:{value}`.
+    Tag,
+    /// Full-text searchable strings.
+    Text,
+    /// Numbers, filtered with `@field:[min max]`.
+    Numeric,
+}
+
+impl FieldType {
+    /// The field type argument for `FT.CREATE` / `FT.ALTER`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FieldType::Tag => "TAG",
+            FieldType::Text => "TEXT",
+            FieldType::Numeric => "NUMERIC",
+        }
+    }
+}
+
+/// What a search does when it exceeds its query timeout, set per engine with
+/// [`RedisEngine::with_on_timeout`](crate::RedisEngine::with_on_timeout) or per query with
+/// [`QueryOptions::on_timeout`](crate::QueryOptions::on_timeout). The server's own `ON_TIMEOUT`
+/// setting is left alone.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OnTimeout {
+    /// Send the timeout as the search's `TIMEOUT`, so the server returns the results found so
+    /// far (under its default `ON_TIMEOUT RETURN`).
+    #[default]
+    Return,
+    /// Wait at most the timeout for the complete reply, then fail with
+    /// [`VectorStoreError::Timeout`](crate::VectorStoreError::Timeout). Partial results are
+    /// never returned; the server finishes the abandoned search in the background.
+    Fail,
+}
+
+/// Byte order of serialized vector blobs (see [`serialize_vector_with`](crate::serialize_vector_with)).
+///
+/// RediSearch reads vector fields and query `PARAMS` blobs as little-endian, so anything sent
+/// to Redis must be `Little`; `Big` is only for reading or writing portable dumps from
+/// producers that used network byte order.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Endianness {
+    #[default]
+    Little,
+    Big,
+}
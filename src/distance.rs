@@ -0,0 +1,76 @@
+//! Client-side vector math for custom reranking, filtering and normalization.
+//!
+//! The distance functions return `NaN` when the inputs have different lengths, so a mismatch
+//! can't silently produce a plausible-looking score.
+
+/// Cosine similarity of `a` and `b`, in `-1.0..=1.0` (1 = same direction).
+///
+/// Returns `0.0` if either vector has zero length (norm) and `NaN` if the lengths differ.
+///
+/// ```
+/// use redis_vector_store::distance::cosine_similarity;
+///
+/// assert_eq!(cosine_similarity(&[1.0, 0.0], &[0.0, 2.0]), 0.0);
+/// assert!((cosine_similarity(&[1.0, 1.0], &[2.0, 2.0]) - 1.0).abs() < 1e-12);
+/// ```
+pub fn cosine_similarity(a: &[f64], b: &[f64]) -> f64 {
+    if a.len() != b.len() {
+        return f64::NAN;
+    }
+    let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
+
+/// Euclidean (L2) distance between `a` and `b`. Returns `NaN` if the lengths differ.
+///
+/// Note that RediSearch's `L2` metric reports the *squared* distance.
+pub fn euclidean_distance(a: &[f64], b: &[f64]) -> f64 {
+    if a.len() != b.len() {
+        return f64::NAN;
+    }
+    a.iter().zip(b).map(|(x, y)| (x - y).powi(2)).sum::<f64>().sqrt()
+}
+
+/// Scale `vector` to unit length in place. Zero vectors are left unchanged.
+///
+/// For unit vectors cosine similarity equals the inner product, and ranks the same as L2
+/// distance, so normalized data can be searched with any of the three metrics.
+pub fn normalize(vector: &mut [f64]) {
+    let norm = vector.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if norm > 0.0 {
+        vector.iter_mut().for_each(|x| *x /= norm);
+    }
+}
+
+/// Unit-length copy of `vector`. Zero vectors are returned unchanged.
+pub fn normalized(vector: &[f64]) -> Vec<f64> {
+    let mut copy = vector.to_vec();
+    normalize(&mut copy);
+    copy
+}
+
+/// Conform `vector` to `target_dim`: zero-pad it if shorter, drop trailing components if
+/// longer. Logs a warning when the length changes.
+///
+/// A pragmatic fix for mixing embedding models against a fixed-dimension collection; see
+/// [`DimensionPolicy`](crate::DimensionPolicy) for what it does to recall.
+///
+/// ```
+/// use redis_vector_store::pad_or_truncate;
+///
+/// assert_eq!(pad_or_truncate(vec![1.0, 2.0], 3), vec![1.0, 2.0, 0.0]);
+/// assert_eq!(pad_or_truncate(vec![1.0, 2.0, 3.0], 2), vec![1.0, 2.0]);
+/// ```
+pub fn pad_or_truncate(mut vector: Vec<f64>, target_dim: usize) -> Vec<f64> {
+    if vector.len() != target_dim {
+        log::warn!("Resizing vector from {} to {} dimensions", vector.len(), target_dim);
+        vector.resize(target_dim, 0.0);
+    }
+    vector
+}
@@ -8,6 +8,29 @@ pub enum VectorStoreError {
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
 
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid UTF-8: {0}")]
+    Utf8(#[from] std::string::FromUtf8Error),
+
+    /// A request made by an HTTP-calling driver (embeddings, token refresh) failed.
+    #[error("HTTP error{status}: {err}", status = http_status(.0), err = .0)]
+    HttpError(#[from] reqwest::Error),
+
+    /// A lookup that requires the item to exist found nothing. `kind` is `"vector"` or `"collection"`.
+    #[error("{kind} not found: {id}")]
+    NotFound { kind: &'static str, id: String },
+
+    /// A search exceeded its timeout under [`OnTimeout::Fail`](crate::OnTimeout::Fail), or
+    /// the server is configured with `ON_TIMEOUT FAIL`.
+    #[error("Query timed out: {0}")]
+    Timeout(String),
+
+    /// A downloaded object does not match its checksum, e.g. a truncated or corrupted blob.
+    #[error("Checksum mismatch for {object}: expected {expected}, got {actual}")]
+    ChecksumMismatch { object: String, expected: String, actual: String },
+
     #[error("{0}")]
     Other(String),
 }
@@ -23,3 +46,20 @@ impl From<&str> for VectorStoreError {
         VectorStoreError::Other(err.to_string())
     }
 }
+
+/// Pass a successful response through. Otherwise log the reply's body and fail with the status
+/// as a [`VectorStoreError::HttpError`], so callers can tell upstream failures apart.
+pub(crate) async fn check_status(response: reqwest::Response, service: &str) -> Result<reqwest::Response, VectorStoreError> {
+    let Some(err) = response.error_for_status_ref().err() else {
+        return Ok(response);
+    };
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    log::error!("{} error {}: {}", service, status, body);
+    Err(err.into())
+}
+
+/// The response status of a failed request, as `" 404 Not Found"`, or nothing if there was none.
+fn http_status(err: &reqwest::Error) -> String {
+    err.status().map(|status| format!(" {}", status)).unwrap_or_default()
+}
@@ -0,0 +1,86 @@
+//! Typed pre-filters for vector queries, compiled to RediSearch query syntax.
+
+use serde::{Deserialize, Serialize};
+
+use crate::redis_engine::escape_tag_value;
+
+/// A filter on indexed fields, restricting which points a KNN, range or hybrid query
+/// considers. Build one from the variants and pass it to
+/// [`QueryOptions::filter`](crate::QueryOptions::filter).
+///
+/// ```
+/// use redis_vector_store::Filter;
+///
+/// let filter = Filter::And(vec![
+///     Filter::tag("source", ["web", "pdf"]),
+///     Filter::Not(Box::new(Filter::tag("namespace", ["tenant-1"]))),
+///     Filter::NumericRange { field: "updated_at".into(), min: Some(1_700_000_000_000.0), max: None },
+/// ]);
+/// assert_eq!(
+///     filter.to_query_string(),
+///     r"(@source:{web | pdf} -(@namespace:{tenant\-1}) @updated_at:[1700000000000 +inf])"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Filter {
+    /// The TAG field holds any of `values`, matched literally. No values matches nothing.
+    Tag { field: String, values: Vec<String> },
+    /// The NUMERIC field lies within `[min, max]`, inclusive; an open bound is unbounded.
+    NumericRange { field: String, min: Option<f64>, max: Option<f64> },
+    /// The TEXT field matches `query`, in RediSearch full-text syntax (words, `"exact phrases"`,
+    /// `prefix*`, `%fuzzy%`, `-negation`), sent as is.
+    Text { field: String, query: String },
+    /// Every filter matches. No filters matches everything.
+    And(Vec<Filter>),
+    /// Any filter matches. No filters matches nothing.
+    Or(Vec<Filter>),
+    /// The filter does not match.
+    Not(Box<Filter>),
+}
+
+impl Filter {
+    /// A [`Filter::Tag`] on `field`.
+    pub fn tag<S: Into<String>>(field: &str, values: impl IntoIterator<Item = S>) -> Self {
+        Filter::Tag { field: field.to_string(), values: values.into_iter().map(Into::into).collect() }
+    }
+
+    /// A [`Filter::Text`] on `field`.
+    pub fn text(field: &str, query: &str) -> Self {
+        Filter::Text { field: field.to_string(), query: query.to_string() }
+    }
+
+    /// The filter as a RediSearch query expression, with tag values escaped.
+    pub fn to_query_string(&self) -> String {
+        match self {
+            Filter::Tag { values, .. } if values.is_empty() => MATCH_NOTHING.to_string(),
+            Filter::Tag { field, values } => {
+                let values: Vec<String> = values.iter().map(|v| escape_tag_value(v)).collect();
+                format!("@{}:{{{}}}", field, values.join(" | "))
+            }
+            Filter::NumericRange { field, min, max } => {
+                let min = min.map_or("-inf".to_string(), |min| min.to_string());
+                let max = max.map_or("+inf".to_string(), |max| max.to_string());
+                format!("@{}:[{} {}]", field, min, max)
+            }
+            Filter::Text { field, query } => format!("@{}:({})", field, query),
+            Filter::And(filters) => match filters.as_slice() {
+                [] => "*".to_string(),
+                [filter] => filter.to_query_string(),
+                filters => format!("({})", join(filters, " ")),
+            },
+            Filter::Or(filters) => match filters.as_slice() {
+                [] => MATCH_NOTHING.to_string(),
+                [filter] => filter.to_query_string(),
+                filters => format!("({})", join(filters, " | ")),
+            },
+            Filter::Not(filter) => format!("-({})", filter.to_query_string()),
+        }
+    }
+}
+
+/// An expression no document matches, for empty unions.
+const MATCH_NOTHING: &str = "-*";
+
+fn join(filters: &[Filter], separator: &str) -> String {
+    filters.iter().map(Filter::to_query_string).collect::<Vec<_>>().join(separator)
+}
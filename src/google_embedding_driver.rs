@@ -2,22 +2,49 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
 use crate::redis_vector_store_driver::EmbeddingDriver;
-use crate::error::VectorStoreError;
+use crate::error::{check_status, VectorStoreError};
 
 /// Google Generative Language API embedding driver.
 ///
 /// Uses the `models/text-embedding-004` endpoint (or any compatible model).
 /// Falls back to a deterministic pseudo-embedding when no API key is provided,
 /// which is useful for testing but NOT suitable for production.
+///
+/// With the `vertex` feature, [`GoogleEmbeddingDriver::vertex`] instead authenticates with a
+/// service account and calls the Vertex AI `predict` endpoint.
 pub struct GoogleEmbeddingDriver {
     model: String,
     api_key: Option<String>,
+    task_type: Option<TaskType>,
+    base_url: String,
     client: reqwest::Client,
+    #[cfg(feature = "vertex")]
+    vertex: Option<vertex::VertexAuth>,
+}
+
+const DEFAULT_BASE_URL: &str = "https://generativelanguage.googleapis.com/v1beta";
+
+/// What an embedding will be used for, sent as the API's `taskType`.
+///
+/// Retrieval works best when documents are embedded with `RetrievalDocument` and queries with
+/// `RetrievalQuery`; the store's driver does this through
+/// [`embed_document`](EmbeddingDriver::embed_document) and [`embed_query`](EmbeddingDriver::embed_query).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TaskType {
+    RetrievalQuery,
+    RetrievalDocument,
+    SemanticSimilarity,
+    Classification,
+    Clustering,
 }
 
 #[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
 struct EmbeddingRequest {
     content: EmbeddingContent,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    task_type: Option<TaskType>,
 }
 
 #[derive(Serialize)]
@@ -49,28 +76,80 @@ impl GoogleEmbeddingDriver {
         Self {
             model: model.to_string(),
             api_key: api_key.map(String::from),
+            task_type: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            client: reqwest::Client::new(),
+            #[cfg(feature = "vertex")]
+            vertex: None,
+        }
+    }
+
+    /// Create a driver for the Vertex AI endpoint, authenticating as a service account.
+    ///
+    /// `model` is the publisher model name, e.g. `"text-embedding-004"` (a `models/` prefix is
+    /// stripped), and `location` the region, e.g. `"us-central1"`. Access tokens are fetched
+    /// from the key's `token_uri` and cached until shortly before they expire.
+    #[cfg(feature = "vertex")]
+    pub fn vertex(model: &str, key: ServiceAccountKey, location: &str) -> Self {
+        let model = model.strip_prefix("models/").unwrap_or(model);
+        Self {
+            model: model.to_string(),
+            api_key: None,
+            task_type: None,
+            base_url: format!("https://{}-aiplatform.googleapis.com/v1", location),
             client: reqwest::Client::new(),
+            vertex: Some(vertex::VertexAuth::new(key, location)),
         }
     }
 
+    /// Use a pre-built HTTP client instead of the default one, e.g. to configure proxies,
+    /// timeouts, connection pooling, or custom TLS roots.
+    pub fn with_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Send requests to `base_url` instead of the public Generative Language API
+    /// (default: `https://generativelanguage.googleapis.com/v1beta`). Mostly useful for
+    /// pointing the driver at a local mock server in tests.
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Set the task type used by [`embed_string`](EmbeddingDriver::embed_string) (default: none,
+    /// leaving it to the API). `embed_query` and `embed_document` always send the matching
+    /// retrieval task type.
+    pub fn with_task_type(mut self, task_type: TaskType) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
     pub fn model(&self) -> &str {
         &self.model
     }
-}
 
-#[async_trait]
-impl EmbeddingDriver for GoogleEmbeddingDriver {
-    async fn embed_string(&self, text: &str) -> Result<Vec<f64>, VectorStoreError> {
+    pub fn task_type(&self) -> Option<TaskType> {
+        self.task_type
+    }
+
+    async fn embed(&self, text: &str, task_type: Option<TaskType>) -> Result<Vec<f64>, VectorStoreError> {
+        #[cfg(feature = "vertex")]
+        if let Some(auth) = &self.vertex {
+            return vertex::predict(&self.client, &self.base_url, &self.model, auth, text, task_type).await;
+        }
+
         let api_key = match &self.api_key {
             Some(key) => key.clone(),
             None => {
+                log::debug!("No API key for {}; using deterministic fallback embedding", self.model);
                 return Ok(deterministic_fallback(text, 768));
             }
         };
 
         let url = format!(
-            "https://generativelanguage.googleapis.com/v1beta/{}:embedContent?key={}",
-            self.model, api_key
+            "{}/{}:embedContent?key={}",
+            self.base_url, self.model, api_key
         );
 
         let request_body = EmbeddingRequest {
@@ -79,30 +158,20 @@ impl EmbeddingDriver for GoogleEmbeddingDriver {
                     text: text.to_string(),
                 }],
             },
+            task_type,
         };
 
         let response = self.client
             .post(&url)
             .json(&request_body)
             .send()
-            .await
-            .map_err(|e| VectorStoreError::Other(format!("API request failed: {}", e)))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            return Err(VectorStoreError::Other(format!(
-                "API error {}: {}",
-                status, body
-            )));
-        }
+            .await?;
+
+        let response = check_status(response, "Google API").await?;
 
         let embedding_response: EmbeddingResponse = response
             .json()
-            .await
-            .map_err(|e| VectorStoreError::Other(format!(
-                "Failed to parse API response: {}", e
-            )))?;
+            .await?;
 
         embedding_response
             .embedding
@@ -111,6 +180,210 @@ impl EmbeddingDriver for GoogleEmbeddingDriver {
     }
 }
 
+#[async_trait]
+impl EmbeddingDriver for GoogleEmbeddingDriver {
+    async fn embed_string(&self, text: &str) -> Result<Vec<f64>, VectorStoreError> {
+        self.embed(text, self.task_type).await
+    }
+
+    async fn embed_query(&self, text: &str) -> Result<Vec<f64>, VectorStoreError> {
+        self.embed(text, Some(TaskType::RetrievalQuery)).await
+    }
+
+    async fn embed_document(&self, text: &str) -> Result<Vec<f64>, VectorStoreError> {
+        self.embed(text, Some(TaskType::RetrievalDocument)).await
+    }
+}
+
+#[cfg(feature = "vertex")]
+pub use vertex::ServiceAccountKey;
+
+#[cfg(feature = "vertex")]
+mod vertex {
+    use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+    use serde::{Deserialize, Serialize};
+    use tokio::sync::Mutex;
+
+    use super::TaskType;
+    use crate::error::{check_status, VectorStoreError};
+
+    const SCOPE: &str = "https://www.googleapis.com/auth/cloud-platform";
+    const TOKEN_LIFETIME_SECS: u64 = 3600;
+    /// Refresh this long before the token expires so in-flight requests don't race expiry.
+    const REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+    /// The fields of a service-account JSON key file that token exchange needs.
+    #[derive(Clone, Deserialize)]
+    pub struct ServiceAccountKey {
+        pub client_email: String,
+        pub private_key: String,
+        pub project_id: String,
+        #[serde(default = "default_token_uri")]
+        pub token_uri: String,
+    }
+
+    /// Keeps the private key out of logs.
+    impl std::fmt::Debug for ServiceAccountKey {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("ServiceAccountKey")
+                .field("client_email", &self.client_email)
+                .field("private_key", &"<redacted>")
+                .field("project_id", &self.project_id)
+                .field("token_uri", &self.token_uri)
+                .finish()
+        }
+    }
+
+    fn default_token_uri() -> String {
+        "https://oauth2.googleapis.com/token".to_string()
+    }
+
+    impl ServiceAccountKey {
+        /// Parse a service-account JSON key.
+        pub fn from_json(json: &str) -> Result<Self, VectorStoreError> {
+            Ok(serde_json::from_str(json)?)
+        }
+
+        /// Read a service-account JSON key file.
+        pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self, VectorStoreError> {
+            Self::from_json(&std::fs::read_to_string(path)?)
+        }
+
+        /// Read the key file named by `GOOGLE_APPLICATION_CREDENTIALS`.
+        pub fn from_env() -> Result<Self, VectorStoreError> {
+            let path = std::env::var("GOOGLE_APPLICATION_CREDENTIALS")
+                .map_err(|_| VectorStoreError::Other("GOOGLE_APPLICATION_CREDENTIALS is not set".to_string()))?;
+            Self::from_file(path)
+        }
+    }
+
+    pub(super) struct VertexAuth {
+        key: ServiceAccountKey,
+        location: String,
+        token: Mutex<Option<(String, Instant)>>,
+    }
+
+    #[derive(Serialize)]
+    struct Claims<'a> {
+        iss: &'a str,
+        scope: &'a str,
+        aud: &'a str,
+        iat: u64,
+        exp: u64,
+    }
+
+    #[derive(Deserialize)]
+    struct TokenResponse {
+        access_token: String,
+        expires_in: Option<u64>,
+    }
+
+    #[derive(Serialize)]
+    struct PredictRequest<'a> {
+        instances: [PredictInstance<'a>; 1],
+    }
+
+    #[derive(Serialize)]
+    struct PredictInstance<'a> {
+        content: &'a str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        task_type: Option<TaskType>,
+    }
+
+    #[derive(Deserialize)]
+    struct PredictResponse {
+        predictions: Vec<Prediction>,
+    }
+
+    #[derive(Deserialize)]
+    struct Prediction {
+        embeddings: super::EmbeddingValues,
+    }
+
+    impl VertexAuth {
+        pub(super) fn new(key: ServiceAccountKey, location: &str) -> Self {
+            Self { key, location: location.to_string(), token: Mutex::new(None) }
+        }
+
+        /// A valid access token, exchanging a freshly signed JWT if the cached one is stale.
+        async fn access_token(&self, client: &reqwest::Client) -> Result<String, VectorStoreError> {
+            let mut cached = self.token.lock().await;
+            if let Some((token, expires_at)) = cached.as_ref() {
+                if Instant::now() + REFRESH_MARGIN < *expires_at {
+                    return Ok(token.clone());
+                }
+            }
+
+            let iat = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+            let claims = Claims {
+                iss: &self.key.client_email,
+                scope: SCOPE,
+                aud: &self.key.token_uri,
+                iat,
+                exp: iat + TOKEN_LIFETIME_SECS,
+            };
+            let signing_key = jsonwebtoken::EncodingKey::from_rsa_pem(self.key.private_key.as_bytes())
+                .map_err(|e| VectorStoreError::Other(format!("Invalid service account private key: {}", e)))?;
+            let assertion = jsonwebtoken::encode(&jsonwebtoken::Header::new(jsonwebtoken::Algorithm::RS256), &claims, &signing_key)
+                .map_err(|e| VectorStoreError::Other(format!("Failed to sign token request: {}", e)))?;
+
+            let response = client
+                .post(&self.key.token_uri)
+                .form(&[
+                    ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+                    ("assertion", assertion.as_str()),
+                ])
+                .send()
+                .await?;
+
+            let response = check_status(response, "Token refresh").await?;
+
+            let token: TokenResponse = response
+                .json()
+                .await?;
+            let expires_at = Instant::now() + Duration::from_secs(token.expires_in.unwrap_or(TOKEN_LIFETIME_SECS));
+            *cached = Some((token.access_token.clone(), expires_at));
+            Ok(token.access_token)
+        }
+    }
+
+    pub(super) async fn predict(
+        client: &reqwest::Client,
+        base_url: &str,
+        model: &str,
+        auth: &VertexAuth,
+        text: &str,
+        task_type: Option<TaskType>,
+    ) -> Result<Vec<f64>, VectorStoreError> {
+        let token = auth.access_token(client).await?;
+        let url = format!(
+            "{}/projects/{}/locations/{}/publishers/google/models/{}:predict",
+            base_url, auth.key.project_id, auth.location, model
+        );
+
+        let response = client
+            .post(&url)
+            .bearer_auth(token)
+            .json(&PredictRequest { instances: [PredictInstance { content: text, task_type }] })
+            .send()
+            .await?;
+
+        let response = check_status(response, "Vertex AI").await?;
+
+        let predict_response: PredictResponse = response
+            .json()
+            .await?;
+
+        predict_response
+            .predictions
+            .into_iter()
+            .next()
+            .map(|p| p.embeddings.values)
+            .ok_or_else(|| VectorStoreError::Other("API response missing embedding".to_string()))
+    }
+}
+
 /// Deterministic fallback embedding for testing without an API key.
 fn deterministic_fallback(text: &str, dim: usize) -> Vec<f64> {
     let mut vec = Vec::with_capacity(dim);
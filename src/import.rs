@@ -0,0 +1,160 @@
+use crate::config::RedisConfig;
+use crate::error::VectorStoreError;
+use crate::loader::open_engine;
+use crate::models::{IdStrategy, Metadata, Payload, PointStruct};
+
+/// Convert a LangChain `Document` export (`{page_content, metadata, id?, embedding?}`) into a point.
+///
+/// `page_content` becomes the content and every `metadata` key is kept in [`Metadata::extra`];
+/// a string `metadata.source` (LangChain's file path or URL) also fills [`Metadata::uri`], and
+/// [`Metadata::source`] is set to `"langchain"`. Without an `id`, the content hash is used (see
+/// [`IdStrategy::ContentHash`]). Without an `embedding`, the vector is left empty for the
+/// caller to fill, as
+/// [`import_langchain_jsonl`](crate::redis_vector_store_driver::RedisStackVectorStoreDriver::import_langchain_jsonl) does.
+pub fn from_langchain_document(value: serde_json::Value) -> Result<PointStruct, VectorStoreError> {
+    let serde_json::Value::Object(mut doc) = value else {
+        return Err(VectorStoreError::Other("LangChain document must be a JSON object".to_string()));
+    };
+
+    let content = match doc.remove("page_content") {
+        Some(serde_json::Value::String(content)) => content,
+        _ => return Err(VectorStoreError::Other("LangChain document is missing a string page_content".to_string())),
+    };
+    let extra = match doc.remove("metadata") {
+        Some(serde_json::Value::Object(map)) => map.into_iter().collect(),
+        Some(serde_json::Value::Null) | None => Default::default(),
+        Some(_) => return Err(VectorStoreError::Other("LangChain document metadata must be an object".to_string())),
+    };
+    let vector: Vec<f64> = match doc.remove("embedding") {
+        Some(serde_json::Value::Null) | None => Vec::new(),
+        Some(embedding) => serde_json::from_value(embedding)?,
+    };
+    let id = match doc.remove("id") {
+        Some(serde_json::Value::String(id)) => id,
+        Some(serde_json::Value::Number(id)) => id.to_string(),
+        _ => IdStrategy::ContentHash.generate(&vector, &content),
+    };
+
+    let mut metadata = Metadata::new("", 0, "langchain");
+    metadata.extra = extra;
+    if let Some(uri) = metadata.extra.get("source").and_then(|v| v.as_str()) {
+        metadata.uri = uri.to_string();
+    }
+
+    Ok(PointStruct::new(&id, vector, Payload::new(&content, metadata)))
+}
+
+/// Points written per pipeline by [`import_qdrant_points`].
+pub const QDRANT_IMPORT_BATCH: usize = 500;
+
+/// Convert a Qdrant point (`{id, vector, payload}`, as in snapshots and scroll/export output)
+/// into a point.
+///
+/// `vector` may be a plain array or a map of named vectors; named vectors need `vector_name`
+/// unless there is only one dense vector. Integer and UUID IDs both become strings. A payload
+/// already shaped like [`Payload`] is used as is; otherwise `content` (or `text` /
+/// `page_content`) becomes the content, `uri`, `chunk_id` and `source` fill [`Metadata`], and
+/// every other key goes to [`Metadata::extra`].
+pub fn from_qdrant_point(value: serde_json::Value, vector_name: Option<&str>) -> Result<PointStruct, VectorStoreError> {
+    let serde_json::Value::Object(mut point) = value else {
+        return Err(VectorStoreError::Other("Qdrant point must be a JSON object".to_string()));
+    };
+
+    let id = match point.remove("id") {
+        Some(serde_json::Value::String(id)) => id,
+        Some(serde_json::Value::Number(id)) => id.to_string(),
+        _ => return Err(VectorStoreError::Other("Qdrant point is missing an id".to_string())),
+    };
+    let vector = match (point.remove("vector"), vector_name) {
+        (Some(serde_json::Value::Object(mut named)), Some(name)) => match named.remove(name) {
+            Some(vector) => serde_json::from_value(vector)?,
+            None => return Err(VectorStoreError::Other(format!("Qdrant point {} has no vector named {}", id, name))),
+        },
+        (Some(serde_json::Value::Object(named)), None) => {
+            // Sparse vectors are objects; only plain arrays are dense vectors
+            let mut dense: Vec<_> = named.into_iter().filter(|(_, v)| v.is_array()).collect();
+            if dense.len() != 1 {
+                let names: Vec<String> = dense.into_iter().map(|(name, _)| name).collect();
+                return Err(VectorStoreError::Other(format!(
+                    "Qdrant point {} has named vectors [{}]; pick one by name",
+                    id,
+                    names.join(", ")
+                )));
+            }
+            serde_json::from_value(dense.remove(0).1)?
+        }
+        (Some(vector), _) => serde_json::from_value(vector)?,
+        (None, _) => return Err(VectorStoreError::Other(format!("Qdrant point {} has no vector", id))),
+    };
+
+    let payload = match point.remove("payload") {
+        Some(serde_json::Value::Object(map)) => payload_from_map(map, "qdrant"),
+        Some(serde_json::Value::Null) | None => Payload::new("", Metadata::new("", 0, "qdrant")),
+        Some(_) => return Err(VectorStoreError::Other(format!("Qdrant point {} payload must be an object", id))),
+    };
+
+    Ok(PointStruct::new(&id, vector, payload))
+}
+
+/// Build a payload from a flat metadata object (see [`from_qdrant_point`]), or take it as is if
+/// it is already shaped like [`Payload`]. `default_source` fills [`Metadata::source`] when the
+/// object has no string `source`.
+pub(crate) fn payload_from_map(mut map: serde_json::Map<String, serde_json::Value>, default_source: &str) -> Payload {
+    if map.get("metadata").is_some_and(|m| m.is_object()) && map.get("content").is_some_and(|c| c.is_string()) {
+        if let Ok(payload) = serde_json::from_value(serde_json::Value::Object(map.clone())) {
+            return payload;
+        }
+    }
+
+    let content = ["content", "text", "page_content"]
+        .iter()
+        .find_map(|key| match map.get(*key) {
+            Some(serde_json::Value::String(_)) => map.remove(*key).and_then(|v| v.as_str().map(String::from)),
+            _ => None,
+        })
+        .unwrap_or_default();
+    let take_str = |map: &mut serde_json::Map<String, serde_json::Value>, key: &str| match map.get(key) {
+        Some(serde_json::Value::String(_)) => map.remove(key).and_then(|v| v.as_str().map(String::from)),
+        _ => None,
+    };
+    let uri = take_str(&mut map, "uri").unwrap_or_default();
+    let source = take_str(&mut map, "source").unwrap_or_else(|| default_source.to_string());
+    let chunk_id = match map.get("chunk_id").and_then(|v| v.as_u64()) {
+        Some(chunk_id) => {
+            map.remove("chunk_id");
+            chunk_id as usize
+        }
+        None => 0,
+    };
+
+    let mut metadata = Metadata::new(&uri, chunk_id, &source);
+    metadata.extra = map.into_iter().collect();
+    Payload::new(&content, metadata)
+}
+
+/// Translate Qdrant points (see [`from_qdrant_point`]) and batch-upsert them into the
+/// collection, creating it if needed with the dimension of the first point,
+/// [`QDRANT_IMPORT_BATCH`] points per pipeline.
+///
+/// Every point must convert, or nothing is written. Points with several named vectors are
+/// rejected; convert those with [`from_qdrant_point`] and a vector name instead.
+/// Returns the number of points imported.
+pub async fn import_qdrant_points(
+    redis_config: &RedisConfig,
+    collection_name: &str,
+    points: Vec<serde_json::Value>,
+) -> Result<usize, VectorStoreError> {
+    let points = points
+        .into_iter()
+        .map(|point| from_qdrant_point(point, None))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let dim = points.first().map(|point| point.vector.len());
+    let engine = open_engine(redis_config, collection_name, async { dim }).await?;
+    engine.create_collection().await?;
+    for batch in points.chunks(QDRANT_IMPORT_BATCH) {
+        engine.add_vectors_batch(batch, None).await?;
+    }
+
+    Ok(points.len())
+}
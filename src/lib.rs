@@ -23,23 +23,47 @@
 //! ).await?;
 //!
 //! // Search
-//! let results = driver.query("hello", Some(5), false, Some("my_ns"), None).await?;
+//! let results = driver.query_with(QueryOptions::new().text("hello").count(5).namespace("my_ns")).await?;
 //! # Ok(())
 //! # }
 //! ```
 
 mod config;
+pub mod distance;
 mod error;
+mod filter;
+mod import;
+mod loader;
+mod metrics;
 mod models;
+mod query_options;
 mod redis_engine;
+mod rerank;
 pub mod redis_vector_store_driver;
 pub mod google_embedding_driver;
+pub mod ollama_embedding_driver;
+pub mod cohere_embedding_driver;
+#[cfg(feature = "test-utils")]
+pub mod mock_embedding_driver;
+#[cfg(feature = "server")]
+pub mod server;
 
-pub use config::RedisConfig;
+pub use config::{CollectionConfig, ContentLengthPolicy, DimensionPolicy, DistanceMetric, Endianness, FieldType, OnTimeout, RedisConfig, RetryPolicy, StorageMode, VectorAlgorithm, DEFAULT_DIALECT};
+pub use distance::{cosine_similarity, euclidean_distance, normalize, normalized, pad_or_truncate};
 pub use error::VectorStoreError;
-pub use models::{PointStruct, Payload, Metadata};
-pub use redis_engine::RedisEngine;
-pub use redis_engine::{get_uuid, serialize_vector, deserialize_vector, DEFAULT_VECTOR_DIM};
+pub use filter::Filter;
+pub use import::{from_langchain_document, from_qdrant_point, import_qdrant_points, QDRANT_IMPORT_BATCH};
+pub use loader::{load_vectors_from, load_vectors_from_dir, sync_vectors_from, sync_vectors_from_dir, LoadStats, SyncStats, DEFAULT_LOAD_CONCURRENCY};
+pub use loader::{Checksum, LocalObjectStore, ObjectStore};
+#[cfg(feature = "npy")]
+pub use loader::{load_vectors_from_npy, NPY_LOAD_BATCH};
+#[cfg(feature = "s3")]
+pub use loader::{load_vectors_from_s3, S3ObjectStore};
+pub use metrics::{MetricsObserver, NoopMetrics};
+pub use models::{GenericPointStruct, IdStrategy, PointStruct, Payload, Metadata, MetadataBuilder};
+pub use query_options::QueryOptions;
+pub use redis_engine::{DeletionPlan, IndexStats, KnnCursor, RedisEngine, SplitPipelines};
+pub use redis_engine::{escape_tag_value, get_uuid, serialize_vector, deserialize_vector, serialize_vector_with, deserialize_vector_with, DEFAULT_VECTOR_DIM};
 
 /// Create a new collection with the default vector dimension (768).
 pub async fn create_collection(redis_config: &RedisConfig, collection_name: &str) -> Result<(), VectorStoreError> {
@@ -57,12 +81,40 @@ pub async fn create_collection_with_dim(
     engine.create_collection().await
 }
 
+/// Create a new collection with explicit index settings, e.g. JSON storage.
+pub async fn create_collection_with_config(
+    redis_config: &RedisConfig,
+    collection_name: &str,
+    collection_config: &CollectionConfig,
+) -> Result<(), VectorStoreError> {
+    let engine = RedisEngine::with_config(redis_config, collection_name, collection_config).await?;
+    engine.create_collection().await
+}
+
 /// Delete a collection and all its vectors.
 pub async fn delete_collection(redis_config: &RedisConfig, collection_name: &str) -> Result<(), VectorStoreError> {
     let engine = RedisEngine::new(redis_config, collection_name).await?;
     engine.delete_collection().await
 }
 
+/// Duplicate `src` as a new collection `dst` with the same schema, returning the number of
+/// points copied. See [`RedisEngine::copy_to`].
+pub async fn copy_collection(redis_config: &RedisConfig, src: &str, dst: &str) -> Result<usize, VectorStoreError> {
+    let engine = RedisEngine::new(redis_config, src).await?;
+    engine.copy_to(dst).await
+}
+
+/// Preview [`delete_collection`]: the keys it would delete, without touching anything.
+pub async fn plan_delete_collection(redis_config: &RedisConfig, collection_name: &str) -> Result<DeletionPlan, VectorStoreError> {
+    let engine = RedisEngine::new(redis_config, collection_name).await?;
+    engine.deletion_plan().await
+}
+
+/// List the names of all collections (RediSearch indexes) on the server.
+pub async fn list_collections(redis_config: &RedisConfig) -> Result<Vec<String>, VectorStoreError> {
+    RedisEngine::list_collections(redis_config).await
+}
+
 /// Get collection metadata (name, index status, document count).
 pub async fn get_collection(redis_config: &RedisConfig, collection_name: &str) -> Result<serde_json::Value, VectorStoreError> {
     let engine = RedisEngine::new(redis_config, collection_name).await?;
@@ -71,26 +123,60 @@ pub async fn get_collection(redis_config: &RedisConfig, collection_name: &str) -
 }
 
 /// Retrieve a single vector and its payload by ID.
+///
+/// With `collection_name` set, `vector_id` is used verbatim, colons included. Without it,
+/// `vector_id` is read as a full key `collection:id`, split on its first colon only
+/// (`docs:doc:2024:v1` is ID `doc:2024:v1` in collection `docs`). Prefer [`get_vector_in`],
+/// which never splits.
 pub async fn get_vector(
     redis_config: &RedisConfig,
     vector_id: &str,
     collection_name: Option<&str>,
 ) -> Result<Option<PointStruct>, VectorStoreError> {
-    let (actual_collection, actual_id) = if vector_id.contains(':') {
-        let parts: Vec<&str> = vector_id.split(':').collect();
-        (parts[0], parts[1])
-    } else {
-        (collection_name.unwrap_or("empty"), vector_id)
+    let (actual_collection, actual_id) = match (collection_name, vector_id.split_once(':')) {
+        (Some(collection), _) => (collection, vector_id),
+        (None, Some((collection, id))) => (collection, id),
+        (None, None) => ("empty", vector_id),
     };
 
-    let engine = RedisEngine::new(redis_config, actual_collection).await?;
-    engine.get_vector(actual_id).await
+    get_vector_in(redis_config, actual_collection, actual_id).await
+}
+
+/// Retrieve a single vector and its payload by ID from an explicit collection.
+pub async fn get_vector_in(
+    redis_config: &RedisConfig,
+    collection_name: &str,
+    vector_id: &str,
+) -> Result<Option<PointStruct>, VectorStoreError> {
+    let engine = RedisEngine::new(redis_config, collection_name).await?;
+    engine.get_vector(vector_id).await
+}
+
+/// Like [`get_vector`], deserializing the payload metadata as `M` (see [`GenericPointStruct`]).
+pub async fn get_vector_as<M: serde::de::DeserializeOwned>(
+    redis_config: &RedisConfig,
+    vector_id: &str,
+    collection_name: &str,
+) -> Result<Option<GenericPointStruct<M>>, VectorStoreError> {
+    let engine = RedisEngine::new(redis_config, collection_name).await?;
+    engine.get_vector_as(vector_id).await
+}
+
+/// Like [`get_vector`], but returns [`VectorStoreError::NotFound`] instead of `Ok(None)`.
+pub async fn get_vector_strict(
+    redis_config: &RedisConfig,
+    vector_id: &str,
+    collection_name: Option<&str>,
+) -> Result<PointStruct, VectorStoreError> {
+    get_vector(redis_config, vector_id, collection_name).await?
+        .ok_or_else(|| VectorStoreError::NotFound { kind: "vector", id: vector_id.to_string() })
 }
 
 /// Insert a vector and its metadata into the collection.
-pub async fn add_vector_and_metadata(
+/// Accepts points with the built-in [`Metadata`] or any serializable metadata type.
+pub async fn add_vector_and_metadata<M: serde::Serialize>(
     redis_config: &RedisConfig,
-    point: &PointStruct,
+    point: &GenericPointStruct<M>,
     collection_name: &str,
     namespace: Option<&str>,
 ) -> Result<(String, String), VectorStoreError> {
@@ -112,7 +198,15 @@ pub use redis_vector_store_driver::{
     VectorStoreDriver,
     EmbeddingDriver,
     Entry,
+    UpsertOutcome,
     get_redis_vector_store_driver
 };
 
-pub use google_embedding_driver::get_embedding_driver;
+pub use google_embedding_driver::{get_embedding_driver, TaskType};
+#[cfg(feature = "vertex")]
+pub use google_embedding_driver::ServiceAccountKey;
+
+pub use ollama_embedding_driver::OllamaEmbeddingDriver;
+pub use cohere_embedding_driver::CohereEmbeddingDriver;
+#[cfg(feature = "test-utils")]
+pub use mock_embedding_driver::MockEmbeddingDriver;
@@ -0,0 +1,660 @@
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use async_trait::async_trait;
+use md5::Md5;
+use sha2::{Digest, Sha256};
+use tokio::sync::Semaphore;
+use tokio::task;
+
+use crate::config::{CollectionConfig, RedisConfig};
+use crate::error::VectorStoreError;
+use crate::models::PointStruct;
+use crate::redis_engine::{IndexStats, RedisEngine};
+
+/// Default number of files loaded concurrently.
+pub const DEFAULT_LOAD_CONCURRENCY: usize = 16;
+
+/// Outcome of a bulk load. Counts are per file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LoadStats {
+    /// Number of files found.
+    pub total: usize,
+    /// Files whose points were all inserted.
+    pub succeeded: usize,
+    /// Files that could not be read, parsed or inserted.
+    pub failed: usize,
+    /// `(file, error message)` for every failed file.
+    pub errors: Vec<(String, String)>,
+}
+
+/// Where the bulk loaders read point files from: a local directory ([`LocalObjectStore`]) or
+/// a bucket in a blob store. Object names use `/` separators; their extensions decide how
+/// they are parsed.
+#[async_trait]
+pub trait ObjectStore: Send + Sync {
+    /// Names of every object whose name starts with `prefix` (`""` for all), sorted.
+    async fn list(&self, prefix: &str) -> Result<Vec<String>, VectorStoreError>;
+
+    /// Contents of the object `name`.
+    async fn get(&self, name: &str) -> Result<Vec<u8>, VectorStoreError>;
+
+    /// The checksum the store keeps for `name`, if any (e.g. a bucket's MD5 hash). The
+    /// loaders verify downloaded bytes against it; without one they fall back to a
+    /// `{name}.sha256` sidecar object when present.
+    async fn checksum(&self, _name: &str) -> Result<Option<Checksum>, VectorStoreError> {
+        Ok(None)
+    }
+}
+
+/// An integrity checksum of an object, hex-encoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Checksum {
+    Sha256(String),
+    Md5(String),
+}
+
+impl Checksum {
+    /// Check `bytes` against this checksum; a mismatch is a
+    /// [`VectorStoreError::ChecksumMismatch`] naming `object`.
+    pub fn verify(&self, object: &str, bytes: &[u8]) -> Result<(), VectorStoreError> {
+        let (expected, actual) = match self {
+            Checksum::Sha256(expected) => (expected, hex(&Sha256::digest(bytes))),
+            Checksum::Md5(expected) => (expected, hex(&Md5::digest(bytes))),
+        };
+        if expected.eq_ignore_ascii_case(&actual) {
+            return Ok(());
+        }
+        log::error!("Checksum mismatch for {}: expected {}, got {}", object, expected, actual);
+        Err(VectorStoreError::ChecksumMismatch { object: object.to_string(), expected: expected.clone(), actual })
+    }
+}
+
+/// An [`ObjectStore`] over a local directory tree. Object names are file paths relative to
+/// the root, e.g. `nested/batch.jsonl`.
+#[derive(Debug, Clone)]
+pub struct LocalObjectStore {
+    root: PathBuf,
+}
+
+impl LocalObjectStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+#[async_trait]
+impl ObjectStore for LocalObjectStore {
+    async fn list(&self, prefix: &str) -> Result<Vec<String>, VectorStoreError> {
+        let mut names: Vec<String> = collect_files(&self.root)?
+            .iter()
+            .filter_map(|file| file.strip_prefix(&self.root).ok())
+            .map(|relative| {
+                let parts: Vec<_> = relative.iter().map(|part| part.to_string_lossy()).collect();
+                parts.join("/")
+            })
+            .filter(|name| name.starts_with(prefix))
+            .collect();
+        names.sort();
+        Ok(names)
+    }
+
+    async fn get(&self, name: &str) -> Result<Vec<u8>, VectorStoreError> {
+        Ok(tokio::fs::read(self.root.join(name)).await?)
+    }
+}
+
+/// An [`ObjectStore`] over an Amazon S3 (or S3-compatible) bucket. Object names are keys.
+#[cfg(feature = "s3")]
+#[derive(Debug, Clone)]
+pub struct S3ObjectStore {
+    client: aws_sdk_s3::Client,
+    bucket: String,
+}
+
+#[cfg(feature = "s3")]
+impl S3ObjectStore {
+    pub fn new(client: aws_sdk_s3::Client, bucket: &str) -> Self {
+        Self { client, bucket: bucket.to_string() }
+    }
+
+    /// A store for `bucket` with a client configured from the environment (credentials,
+    /// region and endpoint, as the AWS CLI reads them).
+    pub async fn from_env(bucket: &str) -> Self {
+        let config = aws_config::load_defaults(aws_config::BehaviorVersion::latest()).await;
+        Self::new(aws_sdk_s3::Client::new(&config), bucket)
+    }
+
+    /// The checksum to verify an object against, from its HeadObject response: its
+    /// full-object `ChecksumSHA256` when it has one, otherwise the MD5 in its ETag. The ETag is
+    /// only an MD5 for single-part uploads stored unencrypted or with SSE-S3; for SSE-KMS,
+    /// SSE-C and multipart objects there is no store checksum and sidecars are used instead.
+    pub fn checksum_from_head(head: &aws_sdk_s3::operation::head_object::HeadObjectOutput) -> Option<Checksum> {
+        use aws_sdk_s3::types::{ChecksumType, ServerSideEncryption};
+
+        let sha256 = head
+            .checksum_sha256()
+            .filter(|_| head.checksum_type() != Some(&ChecksumType::Composite))
+            .and_then(|digest| aws_smithy_types::base64::decode(digest).ok())
+            .filter(|digest| digest.len() == 32);
+        if let Some(digest) = sha256 {
+            return Some(Checksum::Sha256(hex(&digest)));
+        }
+
+        let plain_etag = matches!(head.server_side_encryption(), None | Some(ServerSideEncryption::Aes256))
+            && head.sse_customer_algorithm().is_none();
+        head.e_tag()
+            .map(|etag| etag.trim_matches('"'))
+            .filter(|etag| plain_etag && etag.len() == 32 && etag.chars().all(|c| c.is_ascii_hexdigit()))
+            .map(|etag| Checksum::Md5(etag.to_string()))
+    }
+}
+
+#[cfg(feature = "s3")]
+#[async_trait]
+impl ObjectStore for S3ObjectStore {
+    async fn list(&self, prefix: &str) -> Result<Vec<String>, VectorStoreError> {
+        let mut names = Vec::new();
+        let mut continuation_token = None;
+        loop {
+            let page = self
+                .client
+                .list_objects_v2()
+                .bucket(&self.bucket)
+                .prefix(prefix)
+                .set_continuation_token(continuation_token)
+                .send()
+                .await
+                .map_err(s3_error)?;
+            names.extend(page.contents().iter().filter_map(|object| object.key().map(str::to_string)));
+            match page.next_continuation_token() {
+                Some(token) if page.is_truncated() == Some(true) => continuation_token = Some(token.to_string()),
+                _ => break,
+            }
+        }
+        names.sort();
+        Ok(names)
+    }
+
+    async fn get(&self, name: &str) -> Result<Vec<u8>, VectorStoreError> {
+        let object = self.client.get_object().bucket(&self.bucket).key(name).send().await.map_err(s3_error)?;
+        let body = object.body.collect().await.map_err(|e| VectorStoreError::Other(format!("S3 error: {}", e)))?;
+        Ok(body.into_bytes().to_vec())
+    }
+
+    async fn checksum(&self, name: &str) -> Result<Option<Checksum>, VectorStoreError> {
+        let head = self
+            .client
+            .head_object()
+            .bucket(&self.bucket)
+            .key(name)
+            .checksum_mode(aws_sdk_s3::types::ChecksumMode::Enabled)
+            .send()
+            .await
+            .map_err(s3_error)?;
+        Ok(Self::checksum_from_head(&head))
+    }
+}
+
+#[cfg(feature = "s3")]
+fn s3_error<E: std::error::Error + 'static>(e: E) -> VectorStoreError {
+    VectorStoreError::Other(format!("S3 error: {}", aws_sdk_s3::error::DisplayErrorContext(e)))
+}
+
+/// Load every `*.json`, `*.jsonl` and `*.ndjson` file under `path` (recursively) into the collection.
+///
+/// A `.json` file holds a single `PointStruct`; `.jsonl`/`.ndjson` files hold one `PointStruct`
+/// per line and are inserted with a single pipeline per file. Gzipped files (`.json.gz`,
+/// `.jsonl.gz`, `.ndjson.gz`) are decompressed first. Files are loaded concurrently, one task per file sharing a single engine,
+/// with at most `max_concurrency` (default [`DEFAULT_LOAD_CONCURRENCY`]) in flight at once.
+/// All files are attempted even when some fail; check [`LoadStats::failed`] to tell a
+/// complete load from a partial one. Only setup failures (unreadable directory,
+/// unreachable Redis) are returned as errors. A new collection takes its dimension from the
+/// first point of the first file; an existing one keeps its own.
+pub async fn load_vectors_from_dir(
+    redis_config: &RedisConfig,
+    collection_name: &str,
+    path: impl AsRef<Path>,
+    max_concurrency: Option<usize>,
+) -> Result<LoadStats, VectorStoreError> {
+    load_vectors_from(redis_config, collection_name, LocalObjectStore::new(path.as_ref()), "", max_concurrency).await
+}
+
+/// Like [`load_vectors_from_dir`], reading the point files among the objects under `prefix`
+/// in any [`ObjectStore`]. Errors are reported by object name.
+pub async fn load_vectors_from<S: ObjectStore + 'static>(
+    redis_config: &RedisConfig,
+    collection_name: &str,
+    store: S,
+    prefix: &str,
+    max_concurrency: Option<usize>,
+) -> Result<LoadStats, VectorStoreError> {
+    let store = Arc::new(store);
+    let files = point_files(store.as_ref(), prefix).await?;
+
+    let engine = Arc::new(open_engine(redis_config, collection_name, first_point_dim(store.as_ref(), &files)).await?);
+    engine.create_collection().await?;
+
+    let mut stats = LoadStats {
+        total: files.len(),
+        ..LoadStats::default()
+    };
+    for (file, result) in load_files(&engine, &store, files, max_concurrency).await {
+        match result {
+            Ok(ids) => {
+                stats.succeeded += 1;
+                log::debug!("Loaded {} points from {}", ids.len(), file);
+            }
+            Err(e) => {
+                stats.failed += 1;
+                stats.errors.push((file, e));
+            }
+        }
+    }
+
+    Ok(stats)
+}
+
+/// [`load_vectors_from`] an S3 bucket, reading the objects under `prefix` with a client
+/// configured from the environment (see [`S3ObjectStore::from_env`]).
+#[cfg(feature = "s3")]
+pub async fn load_vectors_from_s3(
+    redis_config: &RedisConfig,
+    collection_name: &str,
+    bucket: &str,
+    prefix: &str,
+    max_concurrency: Option<usize>,
+) -> Result<LoadStats, VectorStoreError> {
+    load_vectors_from(redis_config, collection_name, S3ObjectStore::from_env(bucket).await, prefix, max_concurrency).await
+}
+
+/// Outcome of an incremental sync. Counts are per file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SyncStats {
+    /// New files whose points were inserted.
+    pub added: usize,
+    /// Files already synced by an earlier run, left untouched.
+    pub skipped: usize,
+    /// Previously synced files no longer on disk whose points were deleted.
+    pub deleted: usize,
+    /// New files that could not be read, parsed or inserted; they are retried on the next sync.
+    pub failed: usize,
+    /// `(file, error message)` for every failed file.
+    pub errors: Vec<(String, String)>,
+}
+
+/// Like [`load_vectors_from_dir`], but only loads files not loaded by a previous sync, so it
+/// can run periodically against a growing directory.
+///
+/// Synced files are recorded, by path relative to `path`, in a Redis hash `synced:{collection}`
+/// mapping each file to the IDs of its points. A file is never re-read once synced, even if its
+/// contents change. With `delete_missing`, points from recorded files that no longer exist
+/// under `path` are deleted, one pipeline per file, and the files forgotten.
+pub async fn sync_vectors_from_dir(
+    redis_config: &RedisConfig,
+    collection_name: &str,
+    path: impl AsRef<Path>,
+    max_concurrency: Option<usize>,
+    delete_missing: bool,
+) -> Result<SyncStats, VectorStoreError> {
+    let store = LocalObjectStore::new(path.as_ref());
+    sync_vectors_from(redis_config, collection_name, store, "", max_concurrency, delete_missing).await
+}
+
+/// Like [`sync_vectors_from_dir`] for the objects under `prefix` in any [`ObjectStore`];
+/// objects are recorded by name.
+pub async fn sync_vectors_from<S: ObjectStore + 'static>(
+    redis_config: &RedisConfig,
+    collection_name: &str,
+    store: S,
+    prefix: &str,
+    max_concurrency: Option<usize>,
+    delete_missing: bool,
+) -> Result<SyncStats, VectorStoreError> {
+    let store = Arc::new(store);
+    let files = point_files(store.as_ref(), prefix).await?;
+
+    let engine = Arc::new(open_engine(redis_config, collection_name, first_point_dim(store.as_ref(), &files)).await?);
+    engine.create_collection().await?;
+
+    let mut conn = engine.connection();
+    let synced_key = engine.synced_key();
+    let synced: HashMap<String, String> = redis::cmd("HGETALL").arg(&synced_key).query_async(&mut conn).await?;
+
+    let mut stats = SyncStats::default();
+    let mut present = HashSet::new();
+    let mut pending = Vec::new();
+    for (file, sidecar) in files {
+        if synced.contains_key(&file) {
+            stats.skipped += 1;
+        } else {
+            pending.push((file.clone(), sidecar));
+        }
+        present.insert(file);
+    }
+
+    for (file, result) in load_files(&engine, &store, pending, max_concurrency).await {
+        match result {
+            Ok(ids) => {
+                stats.added += 1;
+                redis::cmd("HSET")
+                    .arg(&synced_key)
+                    .arg(&file)
+                    .arg(serde_json::to_string(&ids)?)
+                    .query_async::<()>(&mut conn)
+                    .await?;
+            }
+            Err(e) => {
+                stats.failed += 1;
+                stats.errors.push((file, e));
+            }
+        }
+    }
+
+    if delete_missing {
+        for (name, ids) in synced.iter().filter(|(name, _)| !present.contains(*name)) {
+            let ids: Vec<String> = serde_json::from_str(ids)?;
+            engine.delete_vectors(&ids).await?;
+            redis::cmd("HDEL").arg(&synced_key).arg(name).query_async::<()>(&mut conn).await?;
+            log::debug!("Deleted {} points of removed file {}", ids.len(), name);
+            stats.deleted += 1;
+        }
+    }
+
+    Ok(stats)
+}
+
+/// Points written per pipeline by [`load_vectors_from_npy`].
+#[cfg(feature = "npy")]
+pub const NPY_LOAD_BATCH: usize = 500;
+
+/// Load an `(N, D)` NumPy matrix of embeddings (`float32` or `float64`, as saved by `np.save`)
+/// into the collection, pairing row `i` with line `i` of `metadata_jsonl_path`.
+///
+/// Each metadata line is a JSON object: its `id` (string or integer) becomes the point ID, or the
+/// ID is derived from the vector when absent, and the rest becomes the payload the way
+/// [`from_qdrant_point`](crate::from_qdrant_point) reads payloads (`content`/`text`, `uri`,
+/// `chunk_id`, `source`, everything else in [`Metadata::extra`](crate::Metadata::extra)).
+/// `D` must match the dimension of the existing index; a new collection is created with
+/// dimension `D`. Both files are validated before anything is written. Returns the number of
+/// points loaded.
+#[cfg(feature = "npy")]
+pub async fn load_vectors_from_npy(
+    redis_config: &RedisConfig,
+    collection_name: &str,
+    npy_path: impl AsRef<Path>,
+    metadata_jsonl_path: impl AsRef<Path>,
+) -> Result<usize, VectorStoreError> {
+    let (rows, dim) = read_npy_matrix(&tokio::fs::read(npy_path.as_ref()).await?)?;
+    let metadata = tokio::fs::read(metadata_jsonl_path.as_ref()).await?;
+    let lines: Vec<&[u8]> = metadata
+        .split(|b| *b == b'\n')
+        .filter(|line| !line.iter().all(u8::is_ascii_whitespace))
+        .collect();
+    if lines.len() != rows.len() {
+        return Err(VectorStoreError::Other(format!(
+            "{} has {} rows but {} has {} metadata lines",
+            npy_path.as_ref().display(),
+            rows.len(),
+            metadata_jsonl_path.as_ref().display(),
+            lines.len()
+        )));
+    }
+
+    let mut points = Vec::with_capacity(rows.len());
+    for (vector, line) in rows.into_iter().zip(lines) {
+        let mut map = match serde_json::from_slice(line)? {
+            serde_json::Value::Object(map) => map,
+            serde_json::Value::Null => serde_json::Map::new(),
+            _ => return Err(VectorStoreError::Other("Metadata lines must be JSON objects".to_string())),
+        };
+        let id = match map.remove("id") {
+            Some(serde_json::Value::String(id)) => id,
+            Some(serde_json::Value::Number(id)) => id.to_string(),
+            _ => crate::get_uuid(&vector),
+        };
+        let payload = crate::import::payload_from_map(map, "npy");
+        points.push(PointStruct::new(&id, vector, payload));
+    }
+
+    let engine = RedisEngine::with_dim(redis_config, collection_name, dim).await?;
+    if let Ok(stats) = engine.index_stats().await {
+        if let Some(index_dim) = stats.vector_dim.filter(|d| *d != dim) {
+            return Err(VectorStoreError::Other(format!(
+                "Vector dimension mismatch: collection {} has dimension {}, {} has {}",
+                collection_name,
+                index_dim,
+                npy_path.as_ref().display(),
+                dim
+            )));
+        }
+    }
+    engine.create_collection().await?;
+    for batch in points.chunks(NPY_LOAD_BATCH) {
+        engine.add_vectors_batch(batch, None).await?;
+    }
+
+    Ok(points.len())
+}
+
+/// Parse a 2-D float `.npy` file into its rows and row length.
+#[cfg(feature = "npy")]
+fn read_npy_matrix(bytes: &[u8]) -> Result<(Vec<Vec<f64>>, usize), VectorStoreError> {
+    let npy = npyz::NpyFile::new(bytes)?;
+    let (n, dim) = match *npy.shape() {
+        [n, dim] if dim > 0 => (n as usize, dim as usize),
+        ref shape => {
+            return Err(VectorStoreError::Other(format!("Expected an (N, D) array with D > 0, got shape {:?}", shape)));
+        }
+    };
+    let order = npy.order();
+    let values: Vec<f64> = match npy.dtype() {
+        npyz::DType::Plain(ty) if ty.type_char() == npyz::TypeChar::Float && ty.size_field() == 4 => {
+            npy.into_vec::<f32>()?.into_iter().map(f64::from).collect()
+        }
+        npyz::DType::Plain(ty) if ty.type_char() == npyz::TypeChar::Float && ty.size_field() == 8 => npy.into_vec::<f64>()?,
+        dtype => {
+            return Err(VectorStoreError::Other(format!("Expected a float32 or float64 array, got {}", dtype.descr())));
+        }
+    };
+
+    let rows = match order {
+        npyz::Order::C => values.chunks(dim).map(<[f64]>::to_vec).collect(),
+        // Column-major: element (i, j) is stored at j * n + i
+        npyz::Order::Fortran => (0..n).map(|i| (0..dim).map(|j| values[j * n + i]).collect()).collect(),
+    };
+    Ok((rows, dim))
+}
+
+/// A point file to load, with the name of its `.sha256` sidecar if there is one.
+type PointFile = (String, Option<String>);
+
+/// The point files (by extension, gzipped or not) among the objects under `prefix`.
+async fn point_files(store: &dyn ObjectStore, prefix: &str) -> Result<Vec<PointFile>, VectorStoreError> {
+    let names = store.list(prefix).await?;
+    let all: HashSet<&String> = names.iter().collect();
+    Ok(names
+        .iter()
+        .filter(|name| {
+            let path = Path::new(name);
+            is_point_file(path) || (is_gzip(path) && is_point_file(&path.with_extension("")))
+        })
+        .map(|name| {
+            let sidecar = format!("{}.sha256", name);
+            let sidecar = all.contains(&sidecar).then_some(sidecar);
+            (name.clone(), sidecar)
+        })
+        .collect())
+}
+
+/// Load `files` from `store` concurrently, one task per file sharing `engine`, with at most
+/// `max_concurrency` in flight. Returns each file's name with the inserted point IDs or the
+/// error message, in input order.
+async fn load_files<S: ObjectStore + 'static>(
+    engine: &Arc<RedisEngine>,
+    store: &Arc<S>,
+    files: Vec<PointFile>,
+    max_concurrency: Option<usize>,
+) -> Vec<(String, Result<Vec<String>, String>)> {
+    let permits = Arc::new(Semaphore::new(max_concurrency.unwrap_or(DEFAULT_LOAD_CONCURRENCY).max(1)));
+    let mut tasks = Vec::with_capacity(files.len());
+    for (file, sidecar) in files {
+        let engine = Arc::clone(engine);
+        let store = Arc::clone(store);
+        let permits = Arc::clone(&permits);
+        let name = file.clone();
+        let handle = task::spawn(async move {
+            let _permit = permits.acquire_owned().await.expect("loader semaphore is never closed");
+            load_file(&engine, store.as_ref(), &file, sidecar.as_deref()).await
+        });
+        // Kept outside the task so a panic is still reported against its file
+        tasks.push((name, handle));
+    }
+
+    let mut results = Vec::with_capacity(tasks.len());
+    for (file, handle) in tasks {
+        match handle.await {
+            Ok(Ok(ids)) => results.push((file, Ok(ids))),
+            Ok(Err(e)) => {
+                log::error!("Failed to load {}: {}", file, e);
+                results.push((file, Err(e.to_string())));
+            }
+            Err(e) => {
+                log::error!("Loader task for {} failed: {}", file, e);
+                results.push((file, Err(e.to_string())));
+            }
+        }
+    }
+    results
+}
+
+async fn load_file(
+    engine: &RedisEngine,
+    store: &dyn ObjectStore,
+    name: &str,
+    sidecar: Option<&str>,
+) -> Result<Vec<String>, VectorStoreError> {
+    let bytes = store.get(name).await?;
+    let checksum = match (store.checksum(name).await?, sidecar) {
+        (Some(checksum), _) => Some(checksum),
+        (None, Some(sidecar)) => Some(read_sidecar(store, sidecar).await?),
+        (None, None) => None,
+    };
+    if let Some(checksum) = checksum {
+        checksum.verify(name, &bytes)?;
+    }
+    let points = parse_object(name, &bytes)?;
+    let ids = engine.add_vectors_batch(&points, None).await?;
+    Ok(ids.into_iter().map(|(id, _)| id).collect())
+}
+
+/// Parse the object `name`, decompressing it first when gzipped.
+fn parse_object(name: &str, bytes: &[u8]) -> Result<Vec<PointStruct>, VectorStoreError> {
+    let file = Path::new(name);
+    if is_gzip(file) {
+        // Parse by the inner extension: `batch.jsonl.gz` is JSONL
+        parse_points(&file.with_extension(""), &gunzip(bytes)?)
+    } else {
+        parse_points(file, bytes)
+    }
+}
+
+/// An engine for `collection_name` with the dimension of its existing index, or for a new
+/// collection `new_dim` (the default dimension when that yields `None`).
+pub(crate) async fn open_engine(
+    redis_config: &RedisConfig,
+    collection_name: &str,
+    new_dim: impl Future<Output = Option<usize>>,
+) -> Result<RedisEngine, VectorStoreError> {
+    let engine = RedisEngine::new(redis_config, collection_name).await?;
+    let dim = match engine.index_stats().await {
+        Ok(IndexStats { vector_dim: Some(dim), .. }) => Some(dim),
+        _ => new_dim.await,
+    };
+    Ok(match dim {
+        Some(dim) => engine.sibling(collection_name, &CollectionConfig::new().vector_dim(dim)),
+        None => engine,
+    })
+}
+
+/// The vector length of the first point in the first of `files` that parses, to size a new
+/// collection by.
+async fn first_point_dim(store: &dyn ObjectStore, files: &[PointFile]) -> Option<usize> {
+    for (name, _) in files {
+        let Ok(bytes) = store.get(name).await else { continue };
+        if let Some(point) = parse_object(name, &bytes).ok().and_then(|points| points.into_iter().next()) {
+            return Some(point.vector.len());
+        }
+    }
+    None
+}
+
+/// Parse a file's contents into points: one per line for JSONL, a single point otherwise.
+pub(crate) fn parse_points(file: &Path, bytes: &[u8]) -> Result<Vec<PointStruct>, VectorStoreError> {
+    if is_jsonl(file) {
+        bytes
+            .split(|b| *b == b'\n')
+            .filter(|line| !line.iter().all(u8::is_ascii_whitespace))
+            .map(|line| serde_json::from_slice(line).map_err(VectorStoreError::from))
+            .collect()
+    } else {
+        Ok(vec![serde_json::from_slice(bytes)?])
+    }
+}
+
+/// Read a `.sha256` sidecar: the digest, optionally followed by the file name as written by
+/// `sha256sum`.
+async fn read_sidecar(store: &dyn ObjectStore, sidecar: &str) -> Result<Checksum, VectorStoreError> {
+    let contents = String::from_utf8(store.get(sidecar).await?)?;
+    let digest = contents
+        .split_whitespace()
+        .next()
+        .ok_or_else(|| VectorStoreError::Other(format!("Empty checksum file {}", sidecar)))?;
+    Ok(Checksum::Sha256(digest.to_string()))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn gunzip(bytes: &[u8]) -> Result<Vec<u8>, VectorStoreError> {
+    let mut decompressed = Vec::new();
+    flate2::read::GzDecoder::new(bytes).read_to_end(&mut decompressed)?;
+    Ok(decompressed)
+}
+
+/// Every file under `dir`, recursively.
+fn collect_files(dir: &Path) -> Result<Vec<PathBuf>, VectorStoreError> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(current) = pending.pop() {
+        for entry in std::fs::read_dir(&current)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                pending.push(path);
+            } else {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn is_point_file(path: &Path) -> bool {
+    has_extension(path, "json") || is_jsonl(path)
+}
+
+fn is_gzip(path: &Path) -> bool {
+    has_extension(path, "gz")
+}
+
+fn is_jsonl(path: &Path) -> bool {
+    has_extension(path, "jsonl") || has_extension(path, "ndjson")
+}
+
+fn has_extension(path: &Path, ext: &str) -> bool {
+    path.extension().is_some_and(|e| e == ext)
+}
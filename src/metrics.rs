@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use crate::error::VectorStoreError;
+
+/// Receives timings of driver operations, to forward them to a metrics system (Prometheus,
+/// StatsD, ...) without this crate depending on one. Set it with
+/// [`with_metrics_observer`](crate::redis_vector_store_driver::RedisStackVectorStoreDriver::with_metrics_observer).
+///
+/// Every method defaults to doing nothing, so implement only the ones you need. They are
+/// called inline after each operation, so keep them cheap (e.g. update an atomic counter).
+///
+/// ```
+/// use redis_vector_store::MetricsObserver;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::time::Duration;
+///
+/// #[derive(Default)]
+/// struct QueryCounter(AtomicUsize);
+///
+/// impl MetricsObserver for QueryCounter {
+///     fn on_query(&self, _duration: Duration, _result_count: usize) {
+///         self.0.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+/// ```
+pub trait MetricsObserver: Send + Sync {
+    /// A query (`query_with`, `query_batch`, `query_radius`, `query_multi` and the methods built
+    /// on them) returned `result_count` entries after `duration`.
+    fn on_query(&self, _duration: Duration, _result_count: usize) {}
+
+    /// An upsert of one vector completed after `duration`.
+    fn on_upsert(&self, _duration: Duration) {}
+
+    /// `operation` (`"query"`, `"query_batch"`, `"query_radius"`, `"query_multi"` or
+    /// `"upsert"`) failed with `error`.
+    fn on_error(&self, _operation: &str, _error: &VectorStoreError) {}
+}
+
+/// The default [`MetricsObserver`], which ignores everything.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopMetrics;
+
+impl MetricsObserver for NoopMetrics {}
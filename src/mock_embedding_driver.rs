@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+
+use crate::error::VectorStoreError;
+use crate::redis_vector_store_driver::EmbeddingDriver;
+
+/// Deterministic offline embedding driver for tests (`test-utils` feature).
+///
+/// Hashes the lowercased words of a text and their character trigrams into a unit vector of
+/// the requested dimension. Identical texts always get identical vectors, and texts sharing
+/// words or spellings get closer vectors under cosine distance, so tests can assert on search
+/// order without a real model. Nothing about the vectors is semantic: synonyms are unrelated.
+#[derive(Debug, Clone)]
+pub struct MockEmbeddingDriver {
+    dimension: usize,
+}
+
+impl MockEmbeddingDriver {
+    /// Create a driver producing `dimension`-long vectors.
+    ///
+    /// # Panics
+    ///
+    /// If `dimension` is 0.
+    pub fn new(dimension: usize) -> Self {
+        assert!(dimension > 0, "embedding dimension must be positive");
+        Self { dimension }
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// The embedding of `text`; [`embed_string`](EmbeddingDriver::embed_string) without the `async`.
+    pub fn embed(&self, text: &str) -> Vec<f64> {
+        let mut vector = vec![0.0; self.dimension];
+        let text = text.to_lowercase();
+        let mut add = |feature: &str, weight: f64| {
+            let hash = fnv1a(feature.as_bytes());
+            let sign = if hash >> 63 == 0 { 1.0 } else { -1.0 };
+            vector[(hash % self.dimension as u64) as usize] += sign * weight;
+        };
+
+        for word in text.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()) {
+            add(word, 1.0);
+            let chars: Vec<char> = format!(" {} ", word).chars().collect();
+            for trigram in chars.windows(3) {
+                add(&trigram.iter().collect::<String>(), 0.5);
+            }
+        }
+
+        let norm = vector.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            // Texts without words (or whose features cancel out) still get a valid unit vector
+            vector[0] = 1.0;
+        } else {
+            vector.iter_mut().for_each(|x| *x /= norm);
+        }
+        vector
+    }
+}
+
+#[async_trait]
+impl EmbeddingDriver for MockEmbeddingDriver {
+    async fn embed_string(&self, text: &str) -> Result<Vec<f64>, VectorStoreError> {
+        Ok(self.embed(text))
+    }
+}
+
+/// 64-bit FNV-1a, stable across platforms and releases (unlike `std`'s hashers).
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
 
+use crate::VectorStoreError;
+
 /// Arbitrary key-value metadata attached to each vector.
 ///
 /// `extra` carries any additional fields beyond the three standard ones.
@@ -32,19 +34,91 @@ impl Metadata {
         self.extra.insert(key.to_string(), value);
         self
     }
+
+    /// Start a [`MetadataBuilder`], which checks required fields and cleans the indexed
+    /// `source` tag. [`Metadata::new`] stays permissive.
+    ///
+    /// ```
+    /// use redis_vector_store::Metadata;
+    ///
+    /// let metadata = Metadata::builder().uri("doc.pdf").source("pdf,parser").require_uri(true).build().unwrap();
+    /// assert_eq!(metadata.source, "pdf_parser");
+    /// assert!(Metadata::builder().require_uri(true).build().is_err());
+    /// ```
+    pub fn builder() -> MetadataBuilder {
+        MetadataBuilder::default()
+    }
+}
+
+/// Builder for [`Metadata`], created with [`Metadata::builder`].
+///
+/// `build` replaces the characters RediSearch TAG fields treat specially (`,` `{` `}` `|`) in
+/// `source` with `_`, so a source cannot split into several tags or break a tag filter;
+/// `uri` is kept as given.
+#[derive(Debug, Clone, Default)]
+pub struct MetadataBuilder {
+    uri: String,
+    chunk_id: usize,
+    source: String,
+    extra: HashMap<String, serde_json::Value>,
+    require_uri: bool,
+}
+
+impl MetadataBuilder {
+    pub fn uri(mut self, uri: &str) -> Self {
+        self.uri = uri.to_string();
+        self
+    }
+
+    pub fn chunk_id(mut self, chunk_id: usize) -> Self {
+        self.chunk_id = chunk_id;
+        self
+    }
+
+    pub fn source(mut self, source: &str) -> Self {
+        self.source = source.to_string();
+        self
+    }
+
+    pub fn extra(mut self, key: &str, value: serde_json::Value) -> Self {
+        self.extra.insert(key.to_string(), value);
+        self
+    }
+
+    /// Make `build` fail when `uri` is empty or blank (default: `false`).
+    pub fn require_uri(mut self, require_uri: bool) -> Self {
+        self.require_uri = require_uri;
+        self
+    }
+
+    pub fn build(self) -> Result<Metadata, VectorStoreError> {
+        if self.require_uri && self.uri.trim().is_empty() {
+            return Err(VectorStoreError::Other("Metadata uri must not be empty".to_string()));
+        }
+        let source = self
+            .source
+            .chars()
+            .map(|c| if matches!(c, ',' | '{' | '}' | '|') { '_' } else { c })
+            .collect();
+        Ok(Metadata { uri: self.uri, chunk_id: self.chunk_id, source, extra: self.extra })
+    }
 }
 
 /// The full document payload stored alongside a vector.
+///
+/// `M` is the metadata type: the built-in [`Metadata`] by default, or any serializable type
+/// of your own. A top-level `source` string field in custom metadata is indexed like
+/// [`Metadata::source`].
 #[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct Payload {
+pub struct Payload<M = Metadata> {
     /// Original text content that was embedded.
     pub content: String,
     /// Metadata about the source document.
-    pub metadata: Metadata,
+    pub metadata: M,
 }
 
-impl Payload {
-    pub fn new(content: &str, metadata: Metadata) -> Self {
+impl<M> Payload<M> {
+    pub fn new(content: &str, metadata: M) -> Self {
         Payload {
             content: content.to_string(),
             metadata,
@@ -52,35 +126,85 @@ impl Payload {
     }
 }
 
-/// A vector with its ID and associated payload.
+/// A vector with its ID and a payload whose metadata has type `M`.
+///
+/// Most code uses the [`PointStruct`] alias with the built-in [`Metadata`]; use
+/// `GenericPointStruct<MyMetadata>` with [`RedisEngine::add_vector_and_metadata`](crate::RedisEngine::add_vector_and_metadata)
+/// and [`RedisEngine::get_vector_as`](crate::RedisEngine::get_vector_as) to store typed metadata.
 #[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct PointStruct {
+pub struct GenericPointStruct<M = Metadata> {
     /// Unique identifier for this point.
     pub id: String,
     /// The embedding vector.
     pub vector: Vec<f64>,
     /// The content and metadata.
-    pub payload: Payload,
+    pub payload: Payload<M>,
+    /// When the point was first stored (unix millis). Set by the store; ignored on insert.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<u64>,
+    /// When the point was last written (unix millis). Set by the store; ignored on insert.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<u64>,
+    /// Additional embeddings by vector field name (e.g. `title_vec`), for collections that
+    /// declare them with [`CollectionConfig::vector_field`](crate::CollectionConfig::vector_field).
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub named_vectors: HashMap<String, Vec<f64>>,
 }
 
-impl PointStruct {
-    pub fn new(id: &str, vector: Vec<f64>, payload: Payload) -> Self {
-        PointStruct {
+/// A point with the built-in [`Metadata`].
+pub type PointStruct = GenericPointStruct<Metadata>;
+
+impl<M> GenericPointStruct<M> {
+    pub fn new(id: &str, vector: Vec<f64>, payload: Payload<M>) -> Self {
+        GenericPointStruct {
             id: id.to_string(),
             vector,
             payload,
+            created_at: None,
+            updated_at: None,
+            named_vectors: HashMap::new(),
         }
     }
 
+    /// Set the embedding stored in the named vector field `name`.
+    pub fn with_named_vector(mut self, name: &str, vector: Vec<f64>) -> Self {
+        self.named_vectors.insert(name.to_string(), vector);
+        self
+    }
+
     /// Create a point with an auto-generated (deterministic) UUID based on the vector content.
-    pub fn create(vector: Vec<f64>, content: &str, metadata: Metadata) -> Self {
+    pub fn create(vector: Vec<f64>, content: &str, metadata: M) -> Self {
         let vector_str = format!("{:?}", vector);
         let vector_id = Uuid::new_v5(&Uuid::NAMESPACE_DNS, vector_str.as_bytes()).to_string();
         let payload = Payload::new(content, metadata);
-        PointStruct {
-            id: vector_id,
-            vector,
-            payload,
+        GenericPointStruct::new(&vector_id, vector, payload)
+    }
+}
+
+/// How IDs are generated for points upserted without an explicit ID.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IdStrategy {
+    /// UUIDv5 of the vector's debug formatting. Identical vectors share an ID; the
+    /// result depends on float formatting. Kept as the default for compatibility.
+    #[default]
+    Uuidv5FromVector,
+    /// Random UUIDv4; every upsert creates a new point.
+    Uuidv4Random,
+    /// ULID: random but lexicographically sortable by creation time.
+    Ulid,
+    /// UUIDv5 of the content string, so re-inserting the same document overwrites
+    /// the existing point instead of creating a duplicate.
+    ContentHash,
+}
+
+impl IdStrategy {
+    /// Generate an ID for a point with this vector and content.
+    pub fn generate(&self, vector: &[f64], content: &str) -> String {
+        match self {
+            IdStrategy::Uuidv5FromVector => crate::get_uuid(vector),
+            IdStrategy::Uuidv4Random => Uuid::new_v4().to_string(),
+            IdStrategy::Ulid => ulid::Ulid::new().to_string(),
+            IdStrategy::ContentHash => Uuid::new_v5(&Uuid::NAMESPACE_OID, content.as_bytes()).to_string(),
         }
     }
 }
@@ -0,0 +1,85 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::redis_vector_store_driver::EmbeddingDriver;
+use crate::error::{check_status, VectorStoreError};
+
+/// Ollama local embedding driver.
+///
+/// POSTs to a running Ollama server's `/api/embeddings` endpoint, so the whole pipeline can run
+/// offline against a local model such as `nomic-embed-text`. The vector dimension is whatever
+/// the model produces; create the collection to match.
+pub struct OllamaEmbeddingDriver {
+    model: String,
+    base_url: String,
+    client: reqwest::Client,
+}
+
+pub const DEFAULT_OLLAMA_URL: &str = "http://localhost:11434";
+
+#[derive(Serialize)]
+struct EmbeddingRequest<'a> {
+    model: &'a str,
+    prompt: &'a str,
+}
+
+#[derive(Deserialize)]
+struct EmbeddingResponse {
+    embedding: Option<Vec<f64>>,
+}
+
+impl OllamaEmbeddingDriver {
+    /// Create a new Ollama Embedding Driver for `model` (e.g. `"nomic-embed-text"`), talking to
+    /// the server at [`DEFAULT_OLLAMA_URL`].
+    pub fn new(model: &str) -> Self {
+        Self {
+            model: model.to_string(),
+            base_url: DEFAULT_OLLAMA_URL.to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Talk to the Ollama server at `base_url` instead of [`DEFAULT_OLLAMA_URL`].
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Use a pre-built HTTP client instead of the default one, e.g. to set timeouts.
+    pub fn with_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+}
+
+#[async_trait]
+impl EmbeddingDriver for OllamaEmbeddingDriver {
+    async fn embed_string(&self, text: &str) -> Result<Vec<f64>, VectorStoreError> {
+        let url = format!("{}/api/embeddings", self.base_url);
+
+        let response = self.client
+            .post(&url)
+            .json(&EmbeddingRequest { model: &self.model, prompt: text })
+            .send()
+            .await?;
+
+        let response = check_status(response, "Ollama").await?;
+
+        let embedding_response: EmbeddingResponse = response
+            .json()
+            .await?;
+
+        embedding_response
+            .embedding
+            .filter(|values| !values.is_empty())
+            .ok_or_else(|| VectorStoreError::Other("Ollama response missing embedding".to_string()))
+    }
+}
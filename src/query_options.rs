@@ -0,0 +1,197 @@
+use std::time::Duration;
+
+use crate::config::OnTimeout;
+use crate::filter::Filter;
+
+/// Options for [`VectorStoreDriver::query_with`](crate::VectorStoreDriver::query_with).
+///
+/// ```
+/// use redis_vector_store::QueryOptions;
+///
+/// let opts = QueryOptions::new()
+///     .text("hello world")
+///     .count(10)
+///     .namespace("ns")
+///     .include_vectors(true)
+///     .min_score(0.8);
+/// assert_eq!(opts.count, 10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct QueryOptions {
+    /// Text to embed with the driver's embedding model. Ignored when `query_vector` is set.
+    pub text: String,
+    /// Precomputed query vector; skips embedding.
+    pub query_vector: Option<Vec<f64>>,
+    /// Maximum number of results (default 10).
+    pub count: usize,
+    /// Return stored vectors in each `Entry` (default `false`).
+    pub include_vectors: bool,
+    /// Load each hit's payload into `Entry.meta` (default `true`). When `false`, entries carry
+    /// only IDs, scores (and vectors, if requested) with an empty `meta`, and the query is a
+    /// single `FT.SEARCH` even for hash collections, whose payloads live in separate documents.
+    pub metadata: bool,
+    /// Only search within this namespace.
+    pub namespace: Option<String>,
+    /// Only search within any of these namespaces, ranking their points together. Combined
+    /// with `namespace` when both are set.
+    pub namespaces: Vec<String>,
+    /// Drop results whose [`Entry::similarity`](crate::Entry::similarity) is below this value.
+    pub min_score: Option<f64>,
+    /// Rerank with maximal marginal relevance using this lambda (`1.0` = pure relevance,
+    /// `0.0` = maximum diversity). A pool of `4 * count` candidates is fetched and diversified
+    /// client-side to `count` results.
+    pub mmr: Option<f64>,
+    /// RediSearch query dialect for this query; defaults to [`RedisConfig::dialect`](crate::RedisConfig::dialect).
+    pub dialect: Option<u32>,
+    /// Return these metadata fields straight from the search reply instead of loading each
+    /// hit's full payload; `Entry.meta` then holds only these fields. Empty (the default) loads
+    /// the full payload. See [`RedisEngine::search_knn_fields`](crate::RedisEngine::search_knn_fields)
+    /// for which fields each storage mode can return.
+    pub return_fields: Vec<String>,
+    /// Time budget for the search; defaults to the server's `TIMEOUT`. Whether an expired query
+    /// returns partial results or fails is set by `on_timeout`.
+    pub timeout: Option<Duration>,
+    /// What happens when the search exceeds `timeout`: partial results (the default) or
+    /// [`VectorStoreError::Timeout`](crate::VectorStoreError::Timeout).
+    pub on_timeout: OnTimeout,
+    /// Vector field to search, for collections with several (see
+    /// [`CollectionConfig::vector_field`](crate::CollectionConfig::vector_field)); defaults to `vector`.
+    /// Cannot be combined with `mmr`.
+    pub vector_field: Option<String>,
+    /// Typed pre-filter on indexed fields (tags, numeric ranges, text), combined with the
+    /// namespace filters.
+    pub filter: Option<Filter>,
+    /// RediSearch expression used verbatim as the KNN pre-filter, `(<raw_filter>)=>[KNN ...]`,
+    /// for queries [`Filter`] cannot express. Combined with `filter` and the namespace filters;
+    /// the caller is responsible for escaping (see [`escape_tag_value`](crate::escape_tag_value)).
+    pub raw_filter: Option<String>,
+}
+
+impl Default for QueryOptions {
+    fn default() -> Self {
+        Self {
+            text: String::new(),
+            query_vector: None,
+            count: 10,
+            include_vectors: false,
+            metadata: true,
+            namespace: None,
+            namespaces: Vec::new(),
+            min_score: None,
+            mmr: None,
+            dialect: None,
+            return_fields: Vec::new(),
+            timeout: None,
+            on_timeout: OnTimeout::default(),
+            vector_field: None,
+            filter: None,
+            raw_filter: None,
+        }
+    }
+}
+
+impl QueryOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn text(mut self, text: &str) -> Self {
+        self.text = text.to_string();
+        self
+    }
+
+    pub fn query_vector(mut self, vector: Vec<f64>) -> Self {
+        self.query_vector = Some(vector);
+        self
+    }
+
+    /// Maximum number of results (default 10).
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = count;
+        self
+    }
+
+    pub fn include_vectors(mut self, include: bool) -> Self {
+        self.include_vectors = include;
+        self
+    }
+
+    /// Whether to load each hit's payload into `Entry.meta` (default `true`).
+    pub fn metadata(mut self, metadata: bool) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    pub fn namespace(mut self, namespace: &str) -> Self {
+        self.namespace = Some(namespace.to_string());
+        self
+    }
+
+    /// Search within any of these namespaces, ranked together.
+    pub fn namespaces(mut self, namespaces: &[&str]) -> Self {
+        self.namespaces = namespaces.iter().map(|ns| ns.to_string()).collect();
+        self
+    }
+
+    pub fn min_score(mut self, min_score: f64) -> Self {
+        self.min_score = Some(min_score);
+        self
+    }
+
+    /// Diversify the results with maximal marginal relevance, weighted by `lambda`.
+    pub fn mmr(mut self, lambda: f64) -> Self {
+        self.mmr = Some(lambda);
+        self
+    }
+
+    /// RediSearch query dialect for this query only.
+    pub fn dialect(mut self, dialect: u32) -> Self {
+        self.dialect = Some(dialect);
+        self
+    }
+
+    /// Time budget for the search; see [`on_timeout`](Self::on_timeout).
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Whether a search that exceeds its timeout returns partial results or fails.
+    pub fn on_timeout(mut self, policy: OnTimeout) -> Self {
+        self.on_timeout = policy;
+        self
+    }
+
+    /// Return only these metadata fields in `Entry.meta` instead of the full payload.
+    pub fn return_fields<S: Into<String>>(mut self, fields: impl IntoIterator<Item = S>) -> Self {
+        self.return_fields = fields.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Search this named vector field instead of `vector`.
+    pub fn vector_field(mut self, field: &str) -> Self {
+        self.vector_field = Some(field.to_string());
+        self
+    }
+
+    /// Pre-filter the KNN search on indexed fields.
+    pub fn filter(mut self, filter: Filter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Pre-filter the KNN search with a verbatim RediSearch expression.
+    pub fn raw_filter(mut self, filter: &str) -> Self {
+        self.raw_filter = Some(filter.to_string());
+        self
+    }
+
+    /// `filter` and `raw_filter` as one RediSearch expression; `None` when neither is set.
+    pub(crate) fn pre_filter(&self) -> Option<String> {
+        match (&self.filter, &self.raw_filter) {
+            (Some(filter), Some(raw)) => Some(format!("{} ({})", filter.to_query_string(), raw)),
+            (Some(filter), None) => Some(filter.to_query_string()),
+            (None, raw) => raw.clone(),
+        }
+    }
+}
@@ -1,61 +1,561 @@
-use redis::{Client, RedisResult};
-use redis::aio::ConnectionManager;
-use std::collections::HashMap;
-use byteorder::{ByteOrder, LittleEndian};
+use redis::{Client, Cmd, Pipeline, RedisResult};
+use redis::aio::{ConnectionLike, ConnectionManager};
+use redis::cluster::ClusterClient;
+use redis::cluster_async::ClusterConnection;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tokio::sync::OnceCell;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
 
 use crate::error::VectorStoreError;
-use crate::config::RedisConfig;
-use crate::models::PointStruct;
+use crate::filter::Filter;
+use crate::config::{CollectionConfig, DimensionPolicy, DistanceMetric, Endianness, FieldType, OnTimeout, RedisConfig, RetryPolicy, StorageMode, VectorAlgorithm};
+use crate::models::{GenericPointStruct, Metadata, Payload, PointStruct};
 
 pub const DEFAULT_VECTOR_DIM: usize = 768;
 
+/// Module names, as reported by `MODULE LIST`, that this crate depends on.
+const REQUIRED_MODULES: [&str; 2] = ["search", "ReJSON"];
+
+/// Page size used by [`RedisEngine::search_range`] to walk all matches.
+const RANGE_PAGE_SIZE: usize = 1000;
+
+/// Points copied (and keys renamed) per round-trip by [`RedisEngine::reindex`].
+const REINDEX_BATCH: usize = 500;
+
+/// ID of the legacy placeholder point (see [`CollectionConfig::create_placeholder`]).
+const PLACEHOLDER_ID: &str = "empty";
+
+/// Points of one namespace being copied, with the payload document each should point at.
+type CopyBatch = (Vec<GenericPointStruct<serde_json::Value>>, Vec<Option<String>>);
+
+/// How often [`RedisEngine::wait_for_indexing`] re-checks `FT.INFO`.
+const INDEXING_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Index statistics parsed from `FT.INFO`. Sizes are in megabytes as reported by RediSearch;
+/// fields missing from the reply (older RediSearch versions) are zero.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IndexStats {
+    pub index_name: String,
+    pub num_docs: u64,
+    pub max_doc_id: u64,
+    pub num_terms: u64,
+    pub num_records: u64,
+    pub inverted_sz_mb: f64,
+    pub vector_index_sz_mb: f64,
+    pub doc_table_size_mb: f64,
+    pub key_table_size_mb: f64,
+    pub hash_indexing_failures: u64,
+    /// Whether the index is still scanning existing keys.
+    pub indexing: bool,
+    /// Fraction of existing keys indexed so far, from 0.0 to 1.0.
+    pub percent_indexed: f64,
+    /// `DIM` of the index's vector field, if it reports one.
+    pub vector_dim: Option<usize>,
+}
+
+impl IndexStats {
+    /// Parse an `FT.INFO` reply, either a flat alternating key/value array or a RESP3 map.
+    pub fn from_ft_info(reply: redis::Value) -> Self {
+        let fields = info_fields(reply);
+        let num = |key: &str| fields.get(key).and_then(value_to_f64);
+        IndexStats {
+            index_name: fields.get("index_name").and_then(value_to_string).unwrap_or_default(),
+            num_docs: num("num_docs").unwrap_or(0.0) as u64,
+            max_doc_id: num("max_doc_id").unwrap_or(0.0) as u64,
+            num_terms: num("num_terms").unwrap_or(0.0) as u64,
+            num_records: num("num_records").unwrap_or(0.0) as u64,
+            inverted_sz_mb: num("inverted_sz_mb").unwrap_or(0.0),
+            vector_index_sz_mb: num("vector_index_sz_mb").unwrap_or(0.0),
+            doc_table_size_mb: num("doc_table_size_mb").unwrap_or(0.0),
+            key_table_size_mb: num("key_table_size_mb").unwrap_or(0.0),
+            hash_indexing_failures: num("hash_indexing_failures").unwrap_or(0.0) as u64,
+            indexing: num("indexing").unwrap_or(0.0) != 0.0,
+            percent_indexed: num("percent_indexed").unwrap_or(1.0),
+            vector_dim: fields.get("attributes").and_then(vector_attribute_dim),
+        }
+    }
+}
+
+/// The keys a destructive operation would remove, as reported by
+/// [`RedisEngine::deletion_plan`] without deleting anything.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeletionPlan {
+    /// Keys that would be deleted.
+    pub keys: Vec<String>,
+    /// Number of keys that would be deleted.
+    pub count: usize,
+}
+
+/// A KNN query kept open on the server with `FT.AGGREGATE ... WITHCURSOR`, opened by
+/// [`RedisEngine::search_knn_cursor`] and read one page at a time with [`next_page`](Self::next_page).
+///
+/// Dropping it before the results run out releases the server-side cursor with
+/// `FT.CURSOR DEL` in the background (when dropped inside a Tokio runtime; otherwise
+/// RediSearch expires it once idle).
+pub struct KnnCursor {
+    engine: RedisEngine,
+    id: u64,
+    page_size: usize,
+    first_page: Option<Vec<(String, f64, String)>>,
+}
+
+impl KnnCursor {
+    /// The next page of `(id, score, metadata_json_id)` hits, closest first, or `None` once
+    /// every result has been read.
+    pub async fn next_page(&mut self) -> Result<Option<Vec<(String, f64, String)>>, VectorStoreError> {
+        if let Some(page) = self.first_page.take() {
+            return Ok(Some(page));
+        }
+        if self.id == 0 {
+            return Ok(None);
+        }
+
+        let mut conn = self.engine.conn.clone();
+        let reply: redis::Value = redis::cmd("FT.CURSOR")
+            .arg("READ")
+            .arg(&self.engine.collection_name)
+            .arg(self.id)
+            .arg("COUNT")
+            .arg(self.page_size)
+            .query_async(&mut conn)
+            .await
+            .map_err(|err| search_error(err, &self.engine.collection_name))?;
+        let (page, id) = self.engine.parse_cursor_reply(reply);
+        self.id = id;
+        Ok(Some(page).filter(|page| !page.is_empty() || id != 0))
+    }
+}
+
+impl Drop for KnnCursor {
+    fn drop(&mut self) {
+        if self.id == 0 {
+            return;
+        }
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else { return };
+        let mut conn = self.engine.conn.clone();
+        let mut cmd = redis::cmd("FT.CURSOR");
+        cmd.arg("DEL").arg(&self.engine.collection_name).arg(self.id);
+        runtime.spawn(async move {
+            if let Err(err) = cmd.query_async::<()>(&mut conn).await {
+                log::debug!("Could not release search cursor: {}", err);
+            }
+        });
+    }
+}
+
+/// A single-node or cluster connection.
+#[derive(Clone)]
+enum Connection {
+    Single(ConnectionManager),
+    Cluster(SplitPipelines<ClusterConnection>),
+}
+
+/// Sends plain pipelines through the wrapped connection command by command, in order, so
+/// later writes to a key still follow earlier ones. A cluster rejects pipelines spanning
+/// hash slots, which this crate's pipelines usually do. Atomic (`MULTI`/`EXEC`) pipelines
+/// are passed through unchanged.
+#[derive(Clone)]
+pub struct SplitPipelines<C>(pub C);
+
+impl<C: ConnectionLike + Send> ConnectionLike for SplitPipelines<C> {
+    fn req_packed_command<'a>(&'a mut self, cmd: &'a Cmd) -> redis::RedisFuture<'a, redis::Value> {
+        self.0.req_packed_command(cmd)
+    }
+
+    fn req_packed_commands<'a>(
+        &'a mut self,
+        pipeline: &'a Pipeline,
+        offset: usize,
+        count: usize,
+    ) -> redis::RedisFuture<'a, Vec<redis::Value>> {
+        if offset != 0 || count != pipeline.len() {
+            return self.0.req_packed_commands(pipeline, offset, count);
+        }
+        Box::pin(async move {
+            let mut replies = Vec::with_capacity(count);
+            for cmd in pipeline.cmd_iter() {
+                replies.push(self.0.req_packed_command(cmd).await?);
+            }
+            Ok(replies)
+        })
+    }
+
+    fn get_db(&self) -> i64 {
+        self.0.get_db()
+    }
+}
+
+impl ConnectionLike for Connection {
+    fn req_packed_command<'a>(&'a mut self, cmd: &'a Cmd) -> redis::RedisFuture<'a, redis::Value> {
+        match self {
+            Connection::Single(conn) => conn.req_packed_command(cmd),
+            Connection::Cluster(conn) => conn.req_packed_command(cmd),
+        }
+    }
+
+    fn req_packed_commands<'a>(
+        &'a mut self,
+        pipeline: &'a Pipeline,
+        offset: usize,
+        count: usize,
+    ) -> redis::RedisFuture<'a, Vec<redis::Value>> {
+        match self {
+            Connection::Single(conn) => conn.req_packed_commands(pipeline, offset, count),
+            Connection::Cluster(conn) => conn.req_packed_commands(pipeline, offset, count),
+        }
+    }
+
+    fn get_db(&self) -> i64 {
+        match self {
+            Connection::Single(conn) => conn.get_db(),
+            Connection::Cluster(conn) => conn.get_db(),
+        }
+    }
+}
+
+/// A connection that retries transient failures of every command and pipeline
+/// according to the config's [`RetryPolicy`].
+#[derive(Clone)]
+pub(crate) struct RetryingConnection {
+    inner: Connection,
+    policy: RetryPolicy,
+}
+
+impl RetryingConnection {
+    async fn open(config: &RedisConfig) -> Result<Self, VectorStoreError> {
+        let inner = if config.cluster {
+            let client = ClusterClient::new(vec![config.url.clone()])?;
+            Connection::Cluster(SplitPipelines(client.get_async_connection().await?))
+        } else {
+            let client = Client::open(config.url.clone())?;
+            Connection::Single(ConnectionManager::new(client).await?)
+        };
+        Ok(Self { inner, policy: config.retry_policy })
+    }
+}
+
+impl RetryingConnection {
+    /// Whether this is a cluster connection, where multi-key commands must stay in one slot.
+    fn is_cluster(&self) -> bool {
+        matches!(self.inner, Connection::Cluster(_))
+    }
+
+    /// This connection without retries, for pipelines that must not run twice.
+    fn without_retries(&self) -> Self {
+        Self { inner: self.inner.clone(), policy: RetryPolicy { max_retries: 0, ..self.policy } }
+    }
+}
+
+impl ConnectionLike for RetryingConnection {
+    fn req_packed_command<'a>(&'a mut self, cmd: &'a Cmd) -> redis::RedisFuture<'a, redis::Value> {
+        let inner = &self.inner;
+        Box::pin(self.policy.run(move || {
+            let mut conn = inner.clone();
+            async move { conn.req_packed_command(cmd).await }
+        }))
+    }
+
+    fn req_packed_commands<'a>(
+        &'a mut self,
+        pipeline: &'a Pipeline,
+        offset: usize,
+        count: usize,
+    ) -> redis::RedisFuture<'a, Vec<redis::Value>> {
+        let inner = &self.inner;
+        Box::pin(self.policy.run(move || {
+            let mut conn = inner.clone();
+            async move { conn.req_packed_commands(pipeline, offset, count).await }
+        }))
+    }
+
+    fn get_db(&self) -> i64 {
+        self.inner.get_db()
+    }
+}
+
+/// Low-level access to one collection.
+///
+/// Clones are cheap and share the underlying multiplexed connection (and the cached
+/// "collection exists" check); the connection closes when the last clone is dropped.
+#[derive(Clone)]
 pub struct RedisEngine {
-    conn: ConnectionManager,
+    conn: RetryingConnection,
     collection_name: String,
     vector_dim: usize,
+    dimension_policy: DimensionPolicy,
+    sanitize_non_finite: bool,
+    ensure_collection: bool,
+    collection_ready: Arc<AtomicBool>,
+    copied: Arc<OnceCell<bool>>,
+    ttl: Option<Duration>,
+    storage: StorageMode,
+    distance_metric: DistanceMetric,
+    algorithm: VectorAlgorithm,
+    initial_cap: Option<usize>,
+    block_size: Option<usize>,
+    soft_delete: bool,
+    create_placeholder: bool,
+    index_content: bool,
+    vector_fields: Vec<String>,
+    query_field: String,
+    raw_filter: Option<String>,
+    dialect: u32,
+    query_timeout: Option<Duration>,
+    on_timeout: OnTimeout,
 }
 
 impl RedisEngine {
-    pub async fn new(config: &RedisConfig, collection_name: &str) -> Result<Self, VectorStoreError> {
-        let client = Client::open(config.url.clone())?;
-        let conn = ConnectionManager::new(client).await?;
-        Ok(RedisEngine {
-            conn,
+    /// The engine's (retrying, shared) connection, for crate-internal bookkeeping commands.
+    pub(crate) fn connection(&self) -> RetryingConnection {
+        self.conn.clone()
+    }
+
+    /// The distance metric scores from this engine's searches are measured with.
+    pub(crate) fn distance_metric(&self) -> DistanceMetric {
+        self.distance_metric
+    }
+
+    /// An engine on this connection for another collection with `config`'s index settings,
+    /// keeping this engine's dimension policy, dialect and query timeout.
+    pub(crate) fn sibling(&self, collection_name: &str, config: &CollectionConfig) -> RedisEngine {
+        RedisEngine {
+            conn: self.conn.clone(),
             collection_name: collection_name.to_string(),
-            vector_dim: DEFAULT_VECTOR_DIM,
-        })
+            vector_dim: config.vector_dim,
+            dimension_policy: self.dimension_policy,
+            sanitize_non_finite: self.sanitize_non_finite,
+            ensure_collection: true,
+            collection_ready: Arc::new(AtomicBool::new(false)),
+            copied: Arc::new(OnceCell::new()),
+            ttl: None,
+            storage: config.storage,
+            distance_metric: config.distance_metric,
+            algorithm: config.algorithm,
+            initial_cap: config.initial_cap,
+            block_size: config.block_size,
+            soft_delete: config.soft_delete,
+            create_placeholder: config.create_placeholder,
+            index_content: config.index_content,
+            vector_fields: config.vector_fields.clone(),
+            query_field: "vector".to_string(),
+            raw_filter: None,
+            dialect: self.dialect,
+            query_timeout: self.query_timeout,
+            on_timeout: self.on_timeout,
+        }
+    }
+
+    pub async fn new(config: &RedisConfig, collection_name: &str) -> Result<Self, VectorStoreError> {
+        Self::with_config(config, collection_name, &CollectionConfig::default()).await
     }
 
     pub async fn with_dim(config: &RedisConfig, collection_name: &str, vector_dim: usize) -> Result<Self, VectorStoreError> {
-        let client = Client::open(config.url.clone())?;
-        let conn = ConnectionManager::new(client).await?;
+        Self::with_config(config, collection_name, &CollectionConfig::new().vector_dim(vector_dim)).await
+    }
+
+    /// Create an engine for a collection with explicit index settings (dimension, storage mode).
+    /// Errors if `collection_name` contains `:`, which would make its `{collection}:` key
+    /// prefix overlap another collection's.
+    pub async fn with_config(
+        config: &RedisConfig,
+        collection_name: &str,
+        collection: &CollectionConfig,
+    ) -> Result<Self, VectorStoreError> {
+        check_collection_name(collection_name)?;
+        let conn = RetryingConnection::open(config).await?;
         Ok(RedisEngine {
             conn,
             collection_name: collection_name.to_string(),
-            vector_dim,
+            vector_dim: collection.vector_dim,
+            dimension_policy: DimensionPolicy::default(),
+            sanitize_non_finite: false,
+            ensure_collection: true,
+            collection_ready: Arc::new(AtomicBool::new(false)),
+            copied: Arc::new(OnceCell::new()),
+            ttl: None,
+            storage: collection.storage,
+            distance_metric: collection.distance_metric,
+            algorithm: collection.algorithm,
+            initial_cap: collection.initial_cap,
+            block_size: collection.block_size,
+            soft_delete: collection.soft_delete,
+            create_placeholder: collection.create_placeholder,
+            index_content: collection.index_content,
+            vector_fields: collection.vector_fields.clone(),
+            query_field: "vector".to_string(),
+            raw_filter: None,
+            dialect: config.dialect,
+            query_timeout: None,
+            on_timeout: OnTimeout::default(),
         })
     }
 
+    /// Close this handle. The connection itself is closed once every clone sharing it (e.g.
+    /// the one cached by a driver) is gone; dropping the engine is equivalent.
+    pub fn close(self) {
+        drop(self);
+    }
+
+    /// Set how vectors of the wrong length are handled on insert (default: `Strict`).
+    pub fn with_dimension_policy(mut self, policy: DimensionPolicy) -> Self {
+        self.dimension_policy = policy;
+        self
+    }
+
+    /// Replace `NaN` and infinite vector components with `0.0` on insert, with a logged
+    /// warning, instead of rejecting the point (default: `false`). RediSearch cannot rank
+    /// non-finite vectors meaningfully, so they are never stored as is.
+    pub fn with_sanitize_non_finite(mut self, sanitize: bool) -> Self {
+        self.sanitize_non_finite = sanitize;
+        self
+    }
+
+    /// Whether inserts make sure the index exists first (default: `true`).
+    ///
+    /// The check runs at most once per engine; disable it entirely for bulk loads into a
+    /// collection that is known to exist.
+    pub fn with_ensure_collection(mut self, ensure: bool) -> Self {
+        self.ensure_collection = ensure;
+        self
+    }
+
+    /// Expire every point inserted through this engine after `ttl` (default: never).
+    ///
+    /// Both the vector hash and its metadata document get the TTL. RediSearch drops expired
+    /// hashes from the index lazily, when they are next accessed, so counts may briefly
+    /// include expired documents.
+    pub fn with_ttl(mut self, ttl: Option<Duration>) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Override the RediSearch query dialect taken from the config.
+    pub fn with_dialect(mut self, dialect: u32) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Limit every KNN and range search to `timeout` (default: the server's `TIMEOUT`). What
+    /// happens when it expires is set with [`with_on_timeout`](Self::with_on_timeout).
+    pub fn with_query_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.query_timeout = timeout;
+        self
+    }
+
+    /// Whether searches that exceed the query timeout return partial results or fail with
+    /// [`VectorStoreError::Timeout`] (default: [`OnTimeout::Return`]). Only affects this engine.
+    pub fn with_on_timeout(mut self, policy: OnTimeout) -> Self {
+        self.on_timeout = policy;
+        self
+    }
+
+    /// Run KNN and range searches against the named vector field `field` instead of `vector`
+    /// (see [`CollectionConfig::vector_field`]).
+    pub fn with_vector_field(mut self, field: &str) -> Self {
+        self.query_field = field.to_string();
+        self
+    }
+
+    /// Add a RediSearch expression (e.g. `@updated_at:[1700000000000 +inf]`) to the pre-filter
+    /// of every KNN and range search, as `(<filter>)=>[KNN ...]`. It is sent verbatim: compile it
+    /// from a [`Filter`] or escape tag values with [`escape_tag_value`].
+    pub fn with_raw_filter(mut self, filter: Option<String>) -> Self {
+        self.raw_filter = filter;
+        self
+    }
+
+    /// Serialize as little-endian FLOAT64, the byte order RediSearch expects.
     pub fn serialize_vector(vector: &[f64]) -> Vec<u8> {
+        Self::serialize_vector_with(vector, Endianness::Little)
+    }
+
+    /// Serialize with an explicit byte order. Blobs sent to Redis must be [`Endianness::Little`].
+    pub fn serialize_vector_with(vector: &[f64], endianness: Endianness) -> Vec<u8> {
         let mut bytes = vec![0u8; vector.len() * 8];
         for (i, &val) in vector.iter().enumerate() {
-            LittleEndian::write_f64(&mut bytes[i * 8..(i + 1) * 8], val);
+            let slot = &mut bytes[i * 8..(i + 1) * 8];
+            match endianness {
+                Endianness::Little => LittleEndian::write_f64(slot, val),
+                Endianness::Big => BigEndian::write_f64(slot, val),
+            }
         }
         bytes
     }
 
+    /// Deserialize little-endian FLOAT64 bytes; a trailing partial value is ignored.
     pub fn deserialize_vector(bytes: &[u8]) -> Vec<f64> {
-        let mut vector = Vec::with_capacity(bytes.len() / 8);
-        for chunk in bytes.chunks(8) {
-            if chunk.len() == 8 {
-                vector.push(LittleEndian::read_f64(chunk));
-            }
+        Self::deserialize_vector_with(bytes, Endianness::Little)
+    }
+
+    /// Deserialize bytes written with the given byte order; a trailing partial value is ignored.
+    pub fn deserialize_vector_with(bytes: &[u8], endianness: Endianness) -> Vec<f64> {
+        bytes
+            .chunks_exact(8)
+            .map(|chunk| match endianness {
+                Endianness::Little => LittleEndian::read_f64(chunk),
+                Endianness::Big => BigEndian::read_f64(chunk),
+            })
+            .collect()
+    }
+
+    /// Names of every RediSearch index on the server (`FT._LIST`), sorted.
+    /// Indexes created by other applications are included.
+    pub async fn list_collections(config: &RedisConfig) -> Result<Vec<String>, VectorStoreError> {
+        let mut conn = RetryingConnection::open(config).await?;
+        let mut names: Vec<String> = redis::cmd("FT._LIST").query_async(&mut conn).await?;
+        names.sort();
+        Ok(names)
+    }
+
+    /// Check connectivity with a `PING`.
+    pub async fn ping(&self) -> Result<(), VectorStoreError> {
+        let mut conn = self.conn.clone();
+        redis::cmd("PING").query_async::<()>(&mut conn).await?;
+        Ok(())
+    }
+
+    /// Check that the RediSearch (`search`) and RedisJSON (`ReJSON`) modules are loaded,
+    /// using `MODULE LIST`. The error names every missing module.
+    pub async fn check_modules(&self) -> Result<(), VectorStoreError> {
+        let mut conn = self.conn.clone();
+        let reply: redis::Value = redis::cmd("MODULE").arg("LIST").query_async(&mut conn).await?;
+        let loaded = module_names(&reply);
+
+        let missing: Vec<&str> = REQUIRED_MODULES
+            .iter()
+            .filter(|required| !loaded.iter().any(|name| name.eq_ignore_ascii_case(required)))
+            .copied()
+            .collect();
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(VectorStoreError::Other(format!(
+                "Required Redis module(s) not loaded: {} (is this a Redis Stack server?)",
+                missing.join(", ")
+            )))
         }
-        vector
     }
 
     /// Create a RediSearch index for the collection with vector search capability.
-    /// Schema: vector (FLOAT64), namespace (TAG for filtering), metadata_json_id (TAG).
+    /// Schema: vector (FLOAT64, indexed with [`CollectionConfig::algorithm`] and
+    /// [`CollectionConfig::distance_metric`]), namespace (TAG for filtering), metadata_json_id (TAG, hash
+    /// collections only), source (TAG, the payload's `metadata.source`, for aggregations),
+    /// updated_at (NUMERIC, unix millis, for filtering and sorting by modification time) and,
+    /// with [`CollectionConfig::soft_delete`], deleted (TAG, `1` on tombstoned points).
+    /// JSON collections index the same names as aliases of `$.vector`, `$.namespace`,
+    /// `$.payload.metadata.source`, `$.updated_at` and `$.deleted`. Each
+    /// [`CollectionConfig::vector_field`] adds another vector field like `vector`
+    /// (`$.named_vectors.<name>` for JSON collections), and [`CollectionConfig::index_content`]
+    /// a `content` TEXT field (`$.payload.content`; mirrored into hashes).
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(command = "FT.CREATE", collection = %self.collection_name)))]
     pub async fn create_collection(&self) -> Result<(), VectorStoreError> {
         let mut conn = self.conn.clone();
 
@@ -65,399 +565,2185 @@ impl RedisEngine {
             .await;
 
         if index_exists.is_ok() {
+            log::debug!("Collection {} already exists", self.collection_name);
+            self.collection_ready.store(true, Ordering::Relaxed);
             return Ok(());
         }
 
-        let dim_str = self.vector_dim.to_string();
-        redis::cmd("FT.CREATE")
-            .arg(&self.collection_name)
+        let storage = self.storage;
+        let field = |cmd: &mut redis::Cmd, name: &str, json_path: &str| match storage {
+            StorageMode::Hash => {
+                cmd.arg(name);
+            }
+            StorageMode::Json => {
+                cmd.arg(json_path).arg("AS").arg(name);
+            }
+        };
+
+        let mut cmd = redis::cmd("FT.CREATE");
+        cmd.arg(&self.collection_name)
             .arg("ON")
-            .arg("HASH")
+            .arg(match storage {
+                StorageMode::Hash => "HASH",
+                StorageMode::Json => "JSON",
+            })
             .arg("PREFIX")
             .arg("1")
-            .arg(format!("{}:", self.collection_name))
-            .arg("SCHEMA")
-            .arg("vector")
-            .arg("VECTOR")
-            .arg("FLAT")
-            .arg("6")
-            .arg("TYPE")
-            .arg("FLOAT64")
-            .arg("DIM")
-            .arg(&dim_str)
-            .arg("DISTANCE_METRIC")
-            .arg("COSINE")
-            .arg("namespace")
-            .arg("TAG")
-            .arg("SEPARATOR")
-            .arg("|")
-            .arg("metadata_json_id")
-            .arg("TAG")
-            .query_async::<()>(&mut conn)
-            .await?;
+            .arg(format!("{}:", self.collection_name));
+        if self.create_placeholder {
+            // The placeholder stays out of the index, so searches and counts never see it
+            cmd.arg("FILTER").arg(self.placeholder_filter());
+        }
+        cmd.arg("SCHEMA");
+        let mut attributes = vec![
+            ("TYPE", "FLOAT64".to_string()),
+            ("DIM", self.vector_dim.to_string()),
+            ("DISTANCE_METRIC", self.distance_metric.as_str().to_string()),
+        ];
+        if let Some(initial_cap) = self.initial_cap {
+            attributes.push(("INITIAL_CAP", initial_cap.to_string()));
+        }
+        if let Some(block_size) = self.block_size.filter(|_| self.algorithm == VectorAlgorithm::Flat) {
+            attributes.push(("BLOCK_SIZE", block_size.to_string()));
+        }
+        let vector_fields = std::iter::once(("vector".to_string(), "$.vector".to_string())).chain(
+            self.vector_fields.iter().map(|name| (name.clone(), format!("$.named_vectors.{}", name))),
+        );
+        for (name, json_path) in vector_fields {
+            field(&mut cmd, &name, &json_path);
+            cmd.arg("VECTOR").arg(self.algorithm.as_str()).arg(attributes.len() * 2);
+            for (name, value) in &attributes {
+                cmd.arg(name).arg(value);
+            }
+        }
+        field(&mut cmd, "namespace", "$.namespace");
+        cmd.arg("TAG").arg("SEPARATOR").arg("|");
+        if storage == StorageMode::Hash {
+            cmd.arg("metadata_json_id").arg("TAG");
+        }
+        field(&mut cmd, "source", "$.payload.metadata.source");
+        cmd.arg("TAG");
+        field(&mut cmd, "updated_at", "$.updated_at");
+        cmd.arg("NUMERIC").arg("SORTABLE");
+        if self.soft_delete {
+            field(&mut cmd, "deleted", "$.deleted");
+            cmd.arg("TAG");
+        }
+        if self.index_content {
+            field(&mut cmd, "content", "$.payload.content");
+            cmd.arg("TEXT");
+        }
+        cmd.query_async::<()>(&mut conn).await?;
+        log::info!("Created collection {} ({} dims, {:?} storage)", self.collection_name, self.vector_dim, storage);
+        if self.create_placeholder {
+            self.write_placeholder().await?;
+        }
 
+        self.collection_ready.store(true, Ordering::Relaxed);
         Ok(())
     }
 
-    pub async fn delete_collection(&self) -> Result<(), VectorStoreError> {
+    /// The `FT.CREATE` `FILTER` expression that leaves the placeholder unindexed.
+    fn placeholder_filter(&self) -> String {
+        format!("@__key!=\"{}\"", self.vector_key(PLACEHOLDER_ID))
+    }
+
+    /// Write the legacy `{collection}:empty` zero vector (see [`CollectionConfig::create_placeholder`]).
+    async fn write_placeholder(&self) -> Result<(), VectorStoreError> {
         let mut conn = self.conn.clone();
+        let payload = Payload::new("", Metadata::new("", 0, "placeholder"));
+        let point = PointStruct::new(PLACEHOLDER_ID, vec![0.0; self.vector_dim], payload);
+        let mut pipe = redis::pipe();
+        match self.storage {
+            StorageMode::Hash => {
+                let metadata_id = format!("metadata:{}:empty", self.collection_name);
+                pipe.cmd("HSET")
+                    .arg(self.vector_key(&point.id))
+                    .arg("vector")
+                    .arg(Self::serialize_vector(&point.vector))
+                    .arg("metadata_json_id")
+                    .arg(&metadata_id)
+                    .ignore();
+                pipe.cmd("JSON.SET").arg(&metadata_id).arg("$").arg(serde_json::to_string(&point.payload)?).ignore();
+            }
+            StorageMode::Json => self.queue_json_point(&mut pipe, &self.vector_key(&point.id), &point.vector, &point, None)?,
+        }
+        pipe.query_async::<()>(&mut conn).await?;
+        Ok(())
+    }
 
-        let drop_result: RedisResult<()> = redis::cmd("FT.DROPINDEX")
-            .arg(&self.collection_name)
-            .arg("DD")
-            .query_async(&mut conn)
-            .await;
+    /// Create the collection unless it is already known to exist or the check is disabled.
+    async fn ensure_collection_exists(&self) -> Result<(), VectorStoreError> {
+        if !self.ensure_collection {
+            return Ok(());
+        }
+        self.create_collection_once().await
+    }
 
-        if drop_result.is_ok() {
-            // Clean up orphaned metadata keys (best effort)
-            let metadata_id = format!("metadata:{}:empty", self.collection_name);
-            let _: RedisResult<()> = redis::cmd("JSON.DEL")
-                .arg(&metadata_id)
-                .arg("$")
-                .query_async(&mut conn)
-                .await;
+    /// [`create_collection`](Self::create_collection) unless this engine (or a clone of it)
+    /// already found or created the index, skipping the `FT.INFO` round-trip.
+    pub(crate) async fn create_collection_once(&self) -> Result<(), VectorStoreError> {
+        if self.collection_ready.load(Ordering::Relaxed) {
+            return Ok(());
         }
+        self.create_collection().await
+    }
 
-        drop_result.map_err(VectorStoreError::from)
+    /// Add a field to the collection's existing index with `FT.ALTER ... SCHEMA ADD`, so it can
+    /// be filtered on without dropping and recreating the collection.
+    ///
+    /// JSON collections index `name` from `$.payload.metadata.{name}` (a `$.`-prefixed `name`
+    /// is used as the path and alias). Hash collections index the field of that name on the
+    /// vector hash, which only holds the fields the store writes there (such as `created_at`).
+    /// Existing documents are re-indexed in the background; call
+    /// [`wait_for_indexing`](Self::wait_for_indexing) before relying on the new field.
+    pub async fn add_index_field(&self, name: &str, field_type: FieldType) -> Result<(), VectorStoreError> {
+        let mut conn = self.conn.clone();
+        let mut cmd = redis::cmd("FT.ALTER");
+        cmd.arg(&self.collection_name).arg("SCHEMA").arg("ADD");
+        match self.storage {
+            StorageMode::Hash => {
+                cmd.arg(name);
+            }
+            StorageMode::Json if name.starts_with('$') => {
+                cmd.arg(name).arg("AS").arg(name);
+            }
+            StorageMode::Json => {
+                cmd.arg(format!("$.payload.metadata.{}", name)).arg("AS").arg(name);
+            }
+        }
+        cmd.arg(field_type.as_str());
+        cmd.query_async::<()>(&mut conn).await?;
+        Ok(())
     }
 
-    /// Get vector and its payload by ID.
-    /// JSON.GET without `$` path returns the bare JSON object directly.
-    pub async fn get_vector(&self, vector_id: &str) -> Result<Option<PointStruct>, VectorStoreError> {
+    /// Read the collection's index settings back from `FT.INFO`: storage mode, dimension and
+    /// soft delete, plus any fields added with [`add_index_field`](Self::add_index_field) (in
+    /// the form `add_index_field` takes). `FLAT` sizing is not reported and left at defaults.
+    pub async fn collection_schema(&self) -> Result<(CollectionConfig, Vec<(String, FieldType)>), VectorStoreError> {
+        if !self.index_exists().await? {
+            return Err(VectorStoreError::NotFound { kind: "collection", id: self.collection_name.clone() });
+        }
         let mut conn = self.conn.clone();
-        let full_id = format!("{}:{}", self.collection_name, vector_id);
+        let reply: redis::Value = redis::cmd("FT.INFO").arg(&self.collection_name).query_async(&mut conn).await?;
+        let info = info_fields(reply);
 
-        let exists: bool = redis::cmd("EXISTS")
-            .arg(&full_id)
-            .query_async(&mut conn)
-            .await?;
+        let definition = info.get("index_definition").map(|definition| info_fields(definition.clone())).unwrap_or_default();
+        let storage = match definition.get("key_type").and_then(value_to_string).as_deref() {
+            Some("JSON") => StorageMode::Json,
+            _ => StorageMode::Hash,
+        };
+        let filter = definition.get("filter").and_then(value_to_string);
+        let mut config = CollectionConfig::new()
+            .storage(storage)
+            .create_placeholder(filter.as_deref() == Some(self.placeholder_filter().as_str()));
+        let mut extra_fields = Vec::new();
+
+        let attributes = match info.get("attributes") {
+            Some(redis::Value::Array(attributes)) => attributes.clone(),
+            _ => Vec::new(),
+        };
+        for attribute in attributes {
+            let attribute = info_fields(attribute);
+            let text = |key: &str| attribute.get(key).and_then(value_to_string).unwrap_or_default();
+            let (identifier, name) = (text("identifier"), text("attribute"));
+            match name.as_str() {
+                "vector" => {
+                    if let Some(dim) = attribute.get("dim").and_then(value_to_f64) {
+                        config.vector_dim = dim as usize;
+                    }
+                    if let Some(metric) = DistanceMetric::parse(&text("distance_metric")) {
+                        config.distance_metric = metric;
+                    }
+                    if let Some(algorithm) = VectorAlgorithm::parse(&text("algorithm")) {
+                        config.algorithm = algorithm;
+                    }
+                }
+                "deleted" => config.soft_delete = true,
+                "content" if text("type") == "TEXT" => config.index_content = true,
+                "namespace" | "metadata_json_id" | "source" | "updated_at" => {}
+                _ if text("type") == "VECTOR" => config = config.vector_field(&name),
+                _ => {
+                    let field_type = match text("type").as_str() {
+                        "TAG" => FieldType::Tag,
+                        "TEXT" => FieldType::Text,
+                        "NUMERIC" => FieldType::Numeric,
+                        _ => continue,
+                    };
+                    // JSON fields added by name are indexed from the payload metadata
+                    let field = match storage {
+                        StorageMode::Json if identifier != format!("$.payload.metadata.{}", name) => identifier,
+                        _ => name,
+                    };
+                    extra_fields.push((field, field_type));
+                }
+            }
+        }
 
-        if !exists {
-            return Ok(None);
+        Ok((config, extra_fields))
+    }
+
+    /// Duplicate the collection as `dst`, e.g. for a backup or a blue/green migration.
+    ///
+    /// Creates `dst` with the same schema (see [`collection_schema`](Self::collection_schema)),
+    /// then walks the points with `SCAN` and copies each page with `DUMP`/`RESTORE`, keeping
+    /// TTLs. Hash points get their own payload documents (`metadata:{dst}:{id}`), which later
+    /// upserts keep using (`dst` is marked with a `copied:{dst}` key), so writing to or
+    /// deleting from one collection leaves the other intact. Errors if `dst` already exists.
+    /// Returns the number of points copied.
+    pub async fn copy_to(&self, dst: &str) -> Result<usize, VectorStoreError> {
+        check_collection_name(dst)?;
+        let (config, extra_fields) = self.collection_schema().await?;
+        // Read with the actual layout, whatever this engine was opened with
+        let source = self.sibling(&self.collection_name, &config);
+        let target = self.sibling(dst, &config);
+        if target.index_exists().await? {
+            return Err(VectorStoreError::Other(format!("Collection {} already exists", dst)));
+        }
+        target.create_collection().await?;
+        for (name, field_type) in &extra_fields {
+            target.add_index_field(name, *field_type).await?;
         }
 
-        let vector_data: HashMap<String, Vec<u8>> = redis::cmd("HGETALL")
-            .arg(&full_id)
-            .query_async(&mut conn)
-            .await?;
+        let mut conn = self.conn.clone();
+        if source.storage == StorageMode::Hash {
+            redis::cmd("SET").arg(target.copied_key()).arg(1).query_async::<()>(&mut conn).await?;
+        }
+        let mut copied = 0;
+        let mut cursor = 0;
+        loop {
+            let (next_cursor, ids) = source.scan_ids(cursor, REINDEX_BATCH).await?;
+            if !ids.is_empty() {
+                // SCAN may repeat a key that was already copied
+                let mut exists = redis::pipe();
+                for id in &ids {
+                    exists.cmd("EXISTS").arg(target.vector_key(id));
+                }
+                let found: Vec<bool> = exists.query_async(&mut conn).await?;
+                let ids: Vec<String> = ids.into_iter().zip(found).filter(|(_, found)| !found).map(|(id, _)| id).collect();
+
+                let points: Vec<String> = ids.iter().map(|id| source.vector_key(id)).collect();
+                let restored = source.copy_keys(&points, |i| target.vector_key(&ids[i])).await?;
+                copied += restored.iter().filter(|restored| **restored).count();
+
+                if source.storage == StorageMode::Hash {
+                    let metadata_ids: Vec<String> = source.metadata_ids_of(&ids).await?.into_iter().flatten().collect();
+                    let new_metadata_id = |i: usize| format!("metadata:{}:{}", dst, ids[i]);
+                    source.copy_keys(&metadata_ids, new_metadata_id).await?;
+                    let mut pipe = redis::pipe();
+                    for (i, id) in ids.iter().enumerate().filter(|(i, _)| restored[*i]) {
+                        pipe.cmd("HSET").arg(target.vector_key(id)).arg("metadata_json_id").arg(new_metadata_id(i)).ignore();
+                    }
+                    if !pipe.is_empty() {
+                        pipe.query_async::<()>(&mut conn).await?;
+                    }
+                }
+            }
+            cursor = next_cursor;
+            if cursor == 0 {
+                break;
+            }
+        }
 
-        if vector_data.is_empty() {
-            return Ok(None);
+        log::info!("Copied {} points from collection {} to {}", copied, self.collection_name, dst);
+        Ok(copied)
+    }
+
+    /// Copy each of `keys` to `new_key(i)` with `DUMP`/`RESTORE ... REPLACE`, keeping its TTL.
+    /// Returns whether each key existed (and so was copied).
+    async fn copy_keys(&self, keys: &[String], new_key: impl Fn(usize) -> String) -> Result<Vec<bool>, VectorStoreError> {
+        if keys.is_empty() {
+            return Ok(Vec::new());
         }
+        let mut conn = self.conn.clone();
+        let mut pipe = redis::pipe();
+        for key in keys {
+            pipe.cmd("DUMP").arg(key).cmd("PTTL").arg(key);
+        }
+        let dumps: Vec<(Option<Vec<u8>>, i64)> = pipe.query_async(&mut conn).await?;
 
-        let vector_bytes = vector_data.get("vector")
-            .ok_or_else(|| VectorStoreError::Other("Vector field not found in Redis hash".to_string()))?;
-        let vector = Self::deserialize_vector(vector_bytes);
+        let mut pipe = redis::pipe();
+        for (i, (dump, ttl)) in dumps.iter().enumerate() {
+            if let Some(dump) = dump {
+                // PTTL is -1 without an expiry; RESTORE takes 0 for none
+                pipe.cmd("RESTORE").arg(new_key(i)).arg((*ttl).max(0)).arg(dump).arg("REPLACE").ignore();
+            }
+        }
+        if !pipe.is_empty() {
+            pipe.query_async::<()>(&mut conn).await?;
+        }
+        Ok(dumps.iter().map(|(dump, _)| dump.is_some()).collect())
+    }
 
-        let metadata_json_id_bytes = vector_data.get("metadata_json_id")
-            .ok_or_else(|| VectorStoreError::Other("metadata_json_id field not found in Redis hash".to_string()))?;
-        let metadata_json_id = String::from_utf8(metadata_json_id_bytes.clone())
-            .map_err(|e| VectorStoreError::Other(format!("Invalid UTF-8 in metadata ID: {}", e)))?;
+    /// Migrate the collection to a new schema: another dimension, storage mode, `FLAT` sizing
+    /// or soft-delete setting.
+    ///
+    /// Points are copied in batches into a temporary collection `{name}__reindex` built with
+    /// `new_config`, re-serializing each vector for the new layout (conformed by this engine's
+    /// [`DimensionPolicy`] if the dimension changes) and keeping its namespace, `created_at`
+    /// and tombstone. Tombstoned points are dropped unless `new_config` enables soft delete,
+    /// and TTLs are not carried over. The old index is then dropped, recreated under the
+    /// collection's own name with `new_config`, and each copy is renamed over its old key, so
+    /// no point is ever missing from Redis but searches briefly see a partial collection. The
+    /// dropped tombstones and stale payload documents are deleted afterwards; the temporary
+    /// index is dropped last. An interrupted run never loses a live point.
+    ///
+    /// Writes to the collection must be paused for the duration: a point written after its
+    /// batch was copied is overwritten by the copy, and one written after the copy pass stays
+    /// in the old layout.
+    ///
+    /// Not supported on a cluster, where `RENAME` cannot move keys between hash slots. This
+    /// engine still describes the old schema afterwards; open a new one with `new_config`.
+    /// Returns the number of points migrated.
+    pub async fn reindex(&self, new_config: CollectionConfig) -> Result<usize, VectorStoreError> {
+        let mut conn = self.conn.clone();
+        if conn.is_cluster() {
+            return Err(VectorStoreError::Other("reindex is not supported on a cluster".to_string()));
+        }
+        if !self.index_exists().await? {
+            return Err(VectorStoreError::NotFound { kind: "collection", id: self.collection_name.clone() });
+        }
 
-        let metadata_json: String = redis::cmd("JSON.GET")
-            .arg(&metadata_json_id)
-            .query_async(&mut conn)
-            .await?;
+        let temp = self.sibling(&format!("{}__reindex", self.collection_name), &new_config);
+        // Left over from an interrupted run; DD only deletes the temporary copies
+        let _: RedisResult<()> = redis::cmd("FT.DROPINDEX").arg(&temp.collection_name).arg("DD").query_async(&mut conn).await;
+        temp.create_collection().await?;
+
+        let mut dropped = Vec::new();
+        let mut cursor = 0;
+        loop {
+            let (next_cursor, ids) = self.scan_ids(cursor, REINDEX_BATCH).await?;
+            dropped.extend(self.copy_points(&temp, &ids).await?);
+            cursor = next_cursor;
+            if cursor == 0 {
+                break;
+            }
+        }
 
-        // JSON.GET returns either an array (with $ path) or a bare object (without $)
-        let payload: crate::models::Payload = if metadata_json.trim_start().starts_with('[') {
-            let arr: Vec<crate::models::Payload> = serde_json::from_str(&metadata_json)?;
-            arr.into_iter().next()
-                .ok_or_else(|| VectorStoreError::Other("Empty JSON array in metadata".to_string()))?
-        } else {
-            serde_json::from_str(&metadata_json)?
-        };
+        // The old keys stay readable until their copies are renamed over them
+        redis::cmd("FT.DROPINDEX").arg(&self.collection_name).query_async::<()>(&mut conn).await?;
+        let target = self.sibling(&self.collection_name, &new_config);
+        target.create_collection().await?;
+
+        let mut migrated = HashSet::new();
+        cursor = 0;
+        loop {
+            let (next_cursor, ids) = temp.scan_ids(cursor, REINDEX_BATCH).await?;
+            if !ids.is_empty() {
+                // SCAN may repeat a key that was already moved
+                let mut exists = redis::pipe();
+                for id in &ids {
+                    exists.cmd("EXISTS").arg(temp.vector_key(id));
+                }
+                let found: Vec<bool> = exists.query_async(&mut conn).await?;
+                let ids: Vec<String> = ids.into_iter().zip(found).filter(|(_, found)| *found).map(|(id, _)| id).collect();
+
+                // Hash copies share the old payload document; it is only removed when the new
+                // layout keeps payloads inline
+                let metadata_ids = match new_config.storage {
+                    StorageMode::Hash => vec![None; ids.len()],
+                    StorageMode::Json => self.metadata_ids_of(&ids).await?,
+                };
+                let mut pipe = redis::pipe();
+                for (id, metadata_id) in ids.iter().zip(metadata_ids) {
+                    pipe.cmd("RENAME").arg(temp.vector_key(id)).arg(target.vector_key(id)).ignore();
+                    if let Some(metadata_id) = metadata_id {
+                        pipe.cmd("DEL").arg(metadata_id).ignore();
+                    }
+                }
+                // A replayed RENAME fails on the key it already moved
+                if !pipe.is_empty() {
+                    pipe.query_async::<()>(&mut self.conn.without_retries()).await?;
+                }
+                migrated.extend(ids);
+            }
+            cursor = next_cursor;
+            if cursor == 0 {
+                break;
+            }
+        }
 
-        Ok(Some(PointStruct {
-            id: vector_id.to_string(),
-            vector,
-            payload,
-        }))
+        // Dropped tombstones are still in the old layout; SCAN may also have repeated one that
+        // was migrated, so only IDs that never got a copy are deleted
+        dropped.retain(|id| !migrated.contains(id));
+        for batch in dropped.chunks(REINDEX_BATCH) {
+            let metadata_ids = self.metadata_ids_of(batch).await?;
+            let mut pipe = redis::pipe();
+            for (id, metadata_id) in batch.iter().zip(metadata_ids) {
+                pipe.cmd("DEL").arg(self.vector_key(id)).ignore();
+                if let Some(metadata_id) = metadata_id {
+                    pipe.cmd("DEL").arg(metadata_id).ignore();
+                }
+            }
+            pipe.query_async::<()>(&mut conn).await?;
+        }
+
+        redis::cmd("FT.DROPINDEX").arg(&temp.collection_name).query_async::<()>(&mut conn).await?;
+        log::info!("Reindexed {} points in collection {}", migrated.len(), self.collection_name);
+        Ok(migrated.len())
     }
 
-    pub async fn get_collection_info(&self) -> Result<HashMap<String, serde_json::Value>, VectorStoreError> {
+    /// Copy the points `ids` of this collection into `target`, with their namespace,
+    /// `created_at` and (if `target` supports it) tombstone. Returns the IDs of the tombstoned
+    /// points left behind because `target` has no soft delete.
+    async fn copy_points(&self, target: &RedisEngine, ids: &[String]) -> Result<Vec<String>, VectorStoreError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
         let mut conn = self.conn.clone();
-        let mut result = HashMap::new();
-
-        result.insert("collection_name".to_string(), serde_json::Value::String(self.collection_name.clone()));
+        let points = self.get_vectors_as::<serde_json::Value>(ids).await?;
+
+        // (namespace, tombstoned) per ID
+        let mut pipe = redis::pipe();
+        for id in ids {
+            match self.storage {
+                StorageMode::Hash => pipe.cmd("HMGET").arg(self.vector_key(id)).arg("namespace").arg("deleted"),
+                StorageMode::Json => pipe.cmd("JSON.GET").arg(self.vector_key(id)).arg("$.namespace").arg("$.deleted"),
+            };
+        }
+        let states: Vec<(Option<String>, bool)> = match self.storage {
+            StorageMode::Hash => {
+                let fields: Vec<(Option<String>, Option<String>)> = pipe.query_async(&mut conn).await?;
+                fields.into_iter().map(|(namespace, deleted)| (namespace, deleted.is_some())).collect()
+            }
+            StorageMode::Json => {
+                // With several paths, JSON.GET replies {"$.namespace": [...], "$.deleted": [...]}
+                let docs: Vec<Option<String>> = pipe.query_async(&mut conn).await?;
+                docs.into_iter()
+                    .map(|doc| {
+                        let doc: serde_json::Value = doc.as_deref().map(serde_json::from_str).transpose()?.unwrap_or_default();
+                        let namespace = doc["$.namespace"][0].as_str().map(String::from);
+                        let deleted = doc["$.deleted"].as_array().is_some_and(|matches| !matches.is_empty());
+                        Ok((namespace, deleted))
+                    })
+                    .collect::<Result<_, VectorStoreError>>()?
+            }
+        };
+
+        // Hash copies keep sharing their point's payload document; points from a JSON
+        // collection get one of their own, so no other collection's `metadata:{id}` is touched
+        let metadata_ids = match self.storage {
+            StorageMode::Hash => self.metadata_ids_of(ids).await?,
+            StorageMode::Json => ids.iter().map(|id| Some(format!("metadata:{}:{}", self.collection_name, id))).collect(),
+        };
+
+        let mut by_namespace: HashMap<Option<String>, CopyBatch> = HashMap::new();
+        let mut dropped = Vec::new();
+        let mut fixups = redis::pipe();
+        for ((point, (namespace, deleted)), metadata_id) in points.into_iter().zip(states).zip(metadata_ids) {
+            let Some(mut point) = point else { continue };
+            if deleted && !target.soft_delete {
+                dropped.push(point.id);
+                continue;
+            }
+            // Vector fields the target schema dropped are left behind
+            point.named_vectors.retain(|name, _| target.vector_fields.contains(name));
+            let key = target.vector_key(&point.id);
+            match target.storage {
+                StorageMode::Hash => {
+                    if let Some(created_at) = point.created_at {
+                        fixups.cmd("HSET").arg(&key).arg("created_at").arg(created_at).ignore();
+                    }
+                    if deleted {
+                        fixups.cmd("HSET").arg(&key).arg("deleted").arg("1").ignore();
+                    }
+                }
+                StorageMode::Json => {
+                    if let Some(created_at) = point.created_at {
+                        fixups.cmd("JSON.SET").arg(&key).arg("$.created_at").arg(created_at).ignore();
+                    }
+                    if deleted {
+                        fixups.cmd("JSON.SET").arg(&key).arg("$.deleted").arg("\"1\"").ignore();
+                    }
+                }
+            }
+            let (points, metadata_ids) = by_namespace.entry(namespace).or_default();
+            points.push(point);
+            metadata_ids.push(metadata_id);
+        }
+
+        for (namespace, (points, metadata_ids)) in &by_namespace {
+            target.write_points(points, metadata_ids, namespace.as_deref()).await?;
+        }
+        if !fixups.is_empty() {
+            fixups.query_async::<()>(&mut conn).await?;
+        }
+        Ok(dropped)
+    }
+
+    /// Poll `FT.INFO` until the index has finished its background scan (`indexing == 0` and
+    /// `percent_indexed == 1`). Returns `true` once indexing is complete, or `false` if
+    /// `timeout` elapses first.
+    pub async fn wait_for_indexing(&self, timeout: Duration) -> Result<bool, VectorStoreError> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            let stats = self.index_stats().await?;
+            if !stats.indexing && stats.percent_indexed >= 1.0 {
+                return Ok(true);
+            }
+            if tokio::time::Instant::now() >= deadline {
+                return Ok(false);
+            }
+            tokio::time::sleep(INDEXING_POLL_INTERVAL).await;
+        }
+    }
+
+    /// Memory and indexing statistics for the collection's index.
+    pub async fn index_stats(&self) -> Result<IndexStats, VectorStoreError> {
+        let mut conn = self.conn.clone();
+        let reply: redis::Value = redis::cmd("FT.INFO")
+            .arg(&self.collection_name)
+            .query_async(&mut conn)
+            .await?;
+        Ok(IndexStats::from_ft_info(reply))
+    }
+
+    pub async fn delete_collection(&self) -> Result<(), VectorStoreError> {
+        let mut conn = self.conn.clone();
+
+        // `DD` only deletes the indexed documents, so remove hash collections' separate
+        // metadata documents first
+        if self.storage == StorageMode::Hash && self.index_exists().await? {
+            self.clear().await?;
+        }
+
+        let drop_result: RedisResult<()> = redis::cmd("FT.DROPINDEX")
+            .arg(&self.collection_name)
+            .arg("DD")
+            .query_async(&mut conn)
+            .await;
+
+        self.collection_ready.store(false, Ordering::Relaxed);
+
+        if drop_result.is_ok() {
+            // Clean up the unindexed placeholder and orphaned metadata keys (best effort)
+            let metadata_id = format!("metadata:{}:empty", self.collection_name);
+            let _: RedisResult<()> = redis::cmd("DEL")
+                .arg(self.vector_key(PLACEHOLDER_ID))
+                .arg(&metadata_id)
+                .query_async(&mut conn)
+                .await;
+            let _: RedisResult<()> = redis::cmd("DEL").arg(self.synced_key()).arg(self.copied_key()).query_async(&mut conn).await;
+        }
+
+        drop_result.map_err(VectorStoreError::from)
+    }
+
+    /// Delete every point in the collection but keep its index, so it can be re-seeded
+    /// without recreating the schema. Walks `collection:*` with `SCAN` and deletes each page
+    /// (vector keys and, for hash collections, their payload documents) in one pipeline.
+    /// Returns the number of points deleted.
+    pub async fn clear(&self) -> Result<usize, VectorStoreError> {
+        let mut conn = self.conn.clone();
+        let mut cursor: u64 = 0;
+        let mut deleted = 0;
+
+        loop {
+            let (next_cursor, ids) = self.scan_ids(cursor, 500).await?;
+
+            if !ids.is_empty() {
+                let metadata_ids = self.metadata_ids_of(&ids).await?;
+                let mut pipe = redis::pipe();
+                for (id, metadata_id) in ids.iter().zip(metadata_ids) {
+                    pipe.cmd("DEL").arg(self.vector_key(id));
+                    if let Some(metadata_id) = metadata_id {
+                        pipe.cmd("DEL").arg(metadata_id).ignore();
+                    }
+                }
+                // A replay would count the keys deleted by the first attempt as missing
+                let removed: Vec<usize> = pipe.query_async(&mut conn.without_retries()).await?;
+                deleted += removed.iter().sum::<usize>();
+            }
+
+            cursor = next_cursor;
+            if cursor == 0 {
+                break;
+            }
+        }
+
+        // Nothing synced earlier is in the collection any more
+        let _: () = redis::cmd("DEL").arg(self.synced_key()).query_async(&mut conn).await?;
+
+        Ok(deleted)
+    }
+
+    /// The hash recording which files [`sync_vectors_from_dir`](crate::sync_vectors_from_dir) loaded.
+    pub(crate) fn synced_key(&self) -> String {
+        format!("synced:{}", self.collection_name)
+    }
+
+    /// The key marking a hash collection created by [`copy_to`](Self::copy_to).
+    fn copied_key(&self) -> String {
+        format!("copied:{}", self.collection_name)
+    }
+
+    /// Dry run of [`clear`](Self::clear) and [`delete_collection`](Self::delete_collection):
+    /// the keys either would delete, found with the same `SCAN`, without deleting anything.
+    /// `delete_collection` additionally drops the index.
+    pub async fn deletion_plan(&self) -> Result<DeletionPlan, VectorStoreError> {
+        let mut keys = Vec::new();
+        let mut cursor: u64 = 0;
+
+        loop {
+            let (next_cursor, ids) = self.scan_ids(cursor, 500).await?;
+            let metadata_ids = self.metadata_ids_of(&ids).await?;
+            for (id, metadata_id) in ids.iter().zip(metadata_ids) {
+                keys.push(self.vector_key(id));
+                keys.extend(metadata_id);
+            }
+
+            cursor = next_cursor;
+            if cursor == 0 {
+                break;
+            }
+        }
+
+        let mut conn = self.conn.clone();
+        let synced: bool = redis::cmd("EXISTS").arg(self.synced_key()).query_async(&mut conn).await?;
+        if synced {
+            keys.push(self.synced_key());
+        }
+
+        Ok(DeletionPlan { count: keys.len(), keys })
+    }
+
+    /// Check whether a vector is stored, with a single `EXISTS` and no deserialization.
+    pub async fn exists(&self, vector_id: &str) -> Result<bool, VectorStoreError> {
+        let mut conn = self.conn.clone();
+        let exists: bool = redis::cmd("EXISTS")
+            .arg(format!("{}:{}", self.collection_name, vector_id))
+            .query_async(&mut conn)
+            .await?;
+        Ok(exists)
+    }
+
+    /// Get vector and its payload by ID.
+    /// JSON.GET without `$` path returns the bare JSON object directly.
+    pub async fn get_vector(&self, vector_id: &str) -> Result<Option<PointStruct>, VectorStoreError> {
+        self.get_vector_as(vector_id).await
+    }
+
+    /// Like [`get_vector`](Self::get_vector), deserializing the payload metadata as `M`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(command = "get", collection = %self.collection_name, vector_id)))]
+    pub async fn get_vector_as<M: DeserializeOwned>(&self, vector_id: &str) -> Result<Option<GenericPointStruct<M>>, VectorStoreError> {
+        if self.storage == StorageMode::Json {
+            return Ok(self.get_vectors_json(&[vector_id.to_string()]).await?.pop().flatten());
+        }
+        if !self.exists(vector_id).await? {
+            return Ok(None);
+        }
+
+        let mut conn = self.conn.clone();
+        let full_id = format!("{}:{}", self.collection_name, vector_id);
+
+        let vector_data: HashMap<String, Vec<u8>> = redis::cmd("HGETALL")
+            .arg(&full_id)
+            .query_async(&mut conn)
+            .await?;
+
+        if vector_data.is_empty() {
+            return Ok(None);
+        }
+
+        let vector_bytes = vector_data.get("vector")
+            .ok_or_else(|| VectorStoreError::Other("Vector field not found in Redis hash".to_string()))?;
+        let vector = Self::deserialize_vector(vector_bytes);
+
+        let metadata_json_id_bytes = vector_data.get("metadata_json_id")
+            .ok_or_else(|| VectorStoreError::Other("metadata_json_id field not found in Redis hash".to_string()))?;
+        let metadata_json_id = String::from_utf8(metadata_json_id_bytes.clone())?;
+
+        let metadata_json: String = redis::cmd("JSON.GET")
+            .arg(&metadata_json_id)
+            .query_async(&mut conn)
+            .await?;
+
+        let payload = parse_payload(&metadata_json)?
+            .ok_or_else(|| VectorStoreError::Other("Empty JSON array in metadata".to_string()))?;
+
+        let (created_at, updated_at) = parse_timestamps(&vector_data);
+        Ok(Some(GenericPointStruct {
+            id: vector_id.to_string(),
+            vector,
+            payload,
+            created_at,
+            updated_at,
+            named_vectors: self.parse_named_vectors(&vector_data),
+        }))
+    }
+
+    /// Like [`get_vector`](Self::get_vector), but a missing vector is an error.
+    /// Returns `NotFound { kind: "collection" }` when the collection's index does not exist,
+    /// and `NotFound { kind: "vector" }` when the index exists but the ID does not.
+    pub async fn get_vector_strict(&self, vector_id: &str) -> Result<PointStruct, VectorStoreError> {
+        if let Some(point) = self.get_vector(vector_id).await? {
+            return Ok(point);
+        }
+        if !self.index_exists().await? {
+            return Err(VectorStoreError::NotFound { kind: "collection", id: self.collection_name.clone() });
+        }
+        Err(VectorStoreError::NotFound { kind: "vector", id: vector_id.to_string() })
+    }
+
+    /// Check whether the collection's RediSearch index exists.
+    async fn index_exists(&self) -> Result<bool, VectorStoreError> {
+        let mut conn = self.conn.clone();
+        let info: RedisResult<redis::Value> = redis::cmd("FT.INFO")
+            .arg(&self.collection_name)
+            .query_async(&mut conn)
+            .await;
+        match info {
+            Ok(_) => Ok(true),
+            Err(e) if e.kind() == redis::ErrorKind::Server(redis::ServerErrorKind::ResponseError) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Fetch many points in two round-trips: one pipelined `HGETALL` per ID, then a single
+    /// `JSON.MGET` of the found payloads (one `JSON.GET` round-trip for JSON collections).
+    /// Results are in input order, `None` for missing IDs.
+    pub async fn get_vectors(&self, ids: &[String]) -> Result<Vec<Option<PointStruct>>, VectorStoreError> {
+        self.get_vectors_as(ids).await
+    }
+
+    /// Like [`get_vectors`](Self::get_vectors), deserializing the payload metadata as `M`.
+    pub async fn get_vectors_as<M: DeserializeOwned>(&self, ids: &[String]) -> Result<Vec<Option<GenericPointStruct<M>>>, VectorStoreError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        if self.storage == StorageMode::Json {
+            return self.get_vectors_json(ids).await;
+        }
+
+        let mut conn = self.conn.clone();
+
+        let mut hash_pipe = redis::pipe();
+        for id in ids {
+            hash_pipe.cmd("HGETALL").arg(format!("{}:{}", self.collection_name, id));
+        }
+        let hashes: Vec<HashMap<String, Vec<u8>>> = hash_pipe.query_async(&mut conn).await?;
+
+        // (input index, vector, metadata_json_id, timestamps) for every hash that exists
+        let mut found = Vec::with_capacity(ids.len());
+        for (i, hash) in hashes.into_iter().enumerate() {
+            let (Some(vector_bytes), Some(meta_id)) = (hash.get("vector"), hash.get("metadata_json_id")) else {
+                continue;
+            };
+            let meta_id = String::from_utf8(meta_id.clone())?;
+            let vectors = (Self::deserialize_vector(vector_bytes), self.parse_named_vectors(&hash));
+            found.push((i, vectors, meta_id, parse_timestamps(&hash)));
+        }
+
+        let mut results: Vec<Option<GenericPointStruct<M>>> = ids.iter().map(|_| None).collect();
+        if found.is_empty() {
+            return Ok(results);
+        }
+
+        let meta_ids: Vec<String> = found.iter().map(|(_, _, meta_id, _)| meta_id.clone()).collect();
+        let payloads = self.get_metadata_batch_as(&meta_ids).await?;
+
+        for ((i, (vector, named_vectors), _, (created_at, updated_at)), payload) in found.into_iter().zip(payloads) {
+            if let Some(payload) = payload {
+                results[i] = Some(GenericPointStruct {
+                    id: ids[i].clone(),
+                    vector,
+                    payload,
+                    created_at,
+                    updated_at,
+                    named_vectors,
+                });
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Read many payload documents of a hash collection (`metadata:{id}` keys, as stored in
+    /// each vector's `metadata_json_id`) with a single `JSON.MGET key1 key2 ... $`.
+    /// Results are in input order, `None` for missing documents.
+    ///
+    /// On a cluster, where the keys span hash slots, falls back to a pipelined `JSON.GET` per key.
+    pub async fn get_metadata_batch(&self, metadata_ids: &[String]) -> Result<Vec<Option<Payload>>, VectorStoreError> {
+        self.get_metadata_batch_as(metadata_ids).await
+    }
+
+    async fn get_metadata_batch_as<M: DeserializeOwned>(&self, metadata_ids: &[String]) -> Result<Vec<Option<Payload<M>>>, VectorStoreError> {
+        if metadata_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = self.conn.clone();
+        let docs: Vec<Option<String>> = if conn.is_cluster() {
+            let mut pipe = redis::pipe();
+            for id in metadata_ids {
+                pipe.cmd("JSON.GET").arg(id);
+            }
+            pipe.query_async(&mut conn).await?
+        } else {
+            // With the `$` path each reply is a JSON array of matches, or nil for a missing key
+            redis::cmd("JSON.MGET").arg(metadata_ids).arg("$").query_async(&mut conn).await?
+        };
+
+        docs.into_iter()
+            .map(|doc| match doc {
+                Some(doc) => parse_payload(&doc),
+                None => Ok(None),
+            })
+            .collect()
+    }
+
+    /// Read whole points from a JSON collection with one pipelined `JSON.GET` per ID.
+    async fn get_vectors_json<M: DeserializeOwned>(&self, ids: &[String]) -> Result<Vec<Option<GenericPointStruct<M>>>, VectorStoreError> {
+        let mut conn = self.conn.clone();
+        let mut pipe = redis::pipe();
+        for id in ids {
+            pipe.cmd("JSON.GET").arg(self.vector_key(id));
+        }
+        let docs: Vec<Option<String>> = pipe.query_async(&mut conn).await?;
+
+        ids.iter()
+            .zip(docs)
+            .map(|(id, doc)| {
+                let Some(doc) = doc else { return Ok(None) };
+                let doc: JsonPoint<M> = serde_json::from_str(&doc)?;
+                Ok(Some(GenericPointStruct {
+                    id: id.clone(),
+                    vector: doc.vector,
+                    payload: doc.payload,
+                    created_at: doc.created_at,
+                    updated_at: doc.updated_at,
+                    named_vectors: doc.named_vectors,
+                }))
+            })
+            .collect()
+    }
+
+    /// Fetch one page of vector IDs in the collection.
+    ///
+    /// Wraps a single `SCAN cursor MATCH collection:* COUNT count`; start with cursor `0` and
+    /// keep calling with the returned cursor until it comes back as `0`. As with `SCAN`,
+    /// `count` is a hint and an ID may be returned more than once across pages.
+    pub async fn scan_ids(&self, cursor: u64, count: usize) -> Result<(u64, Vec<String>), VectorStoreError> {
+        let mut conn = self.conn.clone();
+        let prefix = format!("{}:", self.collection_name);
+
+        let (next_cursor, keys): (u64, Vec<String>) = redis::cmd("SCAN")
+            .arg(cursor)
+            .arg("MATCH")
+            .arg(format!("{}*", glob_escape(&prefix)))
+            .arg("COUNT")
+            .arg(count)
+            .query_async(&mut conn)
+            .await?;
+
+        let ids = keys
+            .into_iter()
+            .map(|key| key.strip_prefix(&prefix).map(String::from).unwrap_or(key))
+            .collect();
+
+        Ok((next_cursor, ids))
+    }
+
+    /// Write every point in the collection to `writer` as JSON Lines, one `PointStruct` per line.
+    ///
+    /// Keys are walked with `SCAN` and each point is written as soon as it is loaded, so memory
+    /// use stays flat regardless of collection size. The output can be re-imported with
+    /// `load_vectors_from_dir`. Returns the number of points written.
+    pub async fn export_entries(&self, mut writer: impl Write) -> Result<usize, VectorStoreError> {
+        let mut cursor: u64 = 0;
+        let mut written = 0;
+
+        loop {
+            let (next_cursor, ids) = self.scan_ids(cursor, 100).await?;
+
+            for id in &ids {
+                if let Some(point) = self.get_vector(id).await? {
+                    serde_json::to_writer(&mut writer, &point)?;
+                    writer.write_all(b"\n")?;
+                    written += 1;
+                }
+            }
+
+            cursor = next_cursor;
+            if cursor == 0 {
+                break;
+            }
+        }
+
+        writer.flush()?;
+        Ok(written)
+    }
+
+    /// Number of documents in the collection, via `FT.SEARCH ... * LIMIT 0 0`.
+    /// Soft-deleted points are not counted.
+    pub async fn count(&self) -> Result<usize, VectorStoreError> {
+        let mut conn = self.conn.clone();
+        let result: redis::Value = redis::cmd("FT.SEARCH")
+            .arg(&self.collection_name)
+            .arg(self.live_query(None))
+            .arg("LIMIT")
+            .arg("0")
+            .arg("0")
+            .arg("DIALECT")
+            .arg(self.dialect)
+            .query_async(&mut conn)
+            .await?;
+        Ok(parse_total(&result) as usize)
+    }
+
+    pub async fn get_collection_info(&self) -> Result<HashMap<String, serde_json::Value>, VectorStoreError> {
+        let mut conn = self.conn.clone();
+        let mut result = HashMap::new();
+
+        result.insert("collection_name".to_string(), serde_json::Value::String(self.collection_name.clone()));
+
+        let index_exists: RedisResult<redis::Value> = redis::cmd("FT.INFO")
+            .arg(&self.collection_name)
+            .query_async(&mut conn)
+            .await;
+
+        result.insert("index_exists".to_string(), serde_json::Value::Bool(index_exists.is_ok()));
+
+        let document_count = self.count().await.unwrap_or(0);
+
+        result.insert("document_count".to_string(), serde_json::Value::Number(serde_json::Number::from(document_count)));
+        Ok(result)
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(command = "upsert", collection = %self.collection_name, vector_id = %point.id)))]
+    pub async fn add_vector_and_metadata<M: Serialize>(&self, point: &GenericPointStruct<M>, namespace: Option<&str>) -> Result<(String, String), VectorStoreError> {
+        let (ids, _) = self.upsert_point(point, namespace).await?;
+        Ok(ids)
+    }
+
+    /// Like [`add_vector_and_metadata`](Self::add_vector_and_metadata), also returning whether
+    /// the point was newly created (`true`) or overwrote an existing one (`false`).
+    ///
+    /// Decided by the same pipeline that writes the point, which first checks whether its key exists.
+    /// Errors, writing nothing, on a point [`DimensionPolicy::Skip`] leaves out.
+    pub async fn upsert_point<M: Serialize>(&self, point: &GenericPointStruct<M>, namespace: Option<&str>) -> Result<((String, String), bool), VectorStoreError> {
+        self.ensure_collection_exists().await?;
+
+        if self.skips(point) {
+            return Err(VectorStoreError::Other(format!(
+                "Point {} skipped: its dimensions do not match collection {} (DimensionPolicy::Skip)",
+                point.id, self.collection_name
+            )));
+        }
+
+        let metadata_id = self.existing_metadata_ids(std::slice::from_ref(&point.id)).await?.pop().flatten();
+        // Not retried: a replay would find the key written by the first attempt
+        let mut conn = self.conn.without_retries();
+        let mut pipe = redis::pipe();
+        // The only reply kept: every write queued by queue_point is ignored
+        pipe.cmd("EXISTS").arg(self.vector_key(&point.id));
+        let ids = self.queue_point(&mut pipe, point, namespace, metadata_id.as_deref())?;
+        let (existed,): (bool,) = pipe.query_async(&mut conn).await?;
+
+        Ok((ids, !existed))
+    }
+
+    /// Insert many points in a single pipelined round-trip.
+    /// Returns `(vector_id, metadata_id)` pairs in input order, leaving out points skipped by
+    /// [`DimensionPolicy::Skip`].
+    pub async fn add_vectors_batch<M: Serialize>(&self, points: &[GenericPointStruct<M>], namespace: Option<&str>) -> Result<Vec<(String, String)>, VectorStoreError> {
+        self.ensure_collection_exists().await?;
+
+        let ids: Vec<String> = points.iter().map(|point| point.id.clone()).collect();
+        let metadata_ids = self.existing_metadata_ids(&ids).await?;
+        self.write_points(points, &metadata_ids, namespace).await
+    }
+
+    /// Write `points` in one pipeline, pointing each hash point at its entry of `metadata_ids`
+    /// (`metadata:{id}` when `None`). Leaves out the points [`skips`](Self::skips) rejects.
+    async fn write_points<M: Serialize>(
+        &self,
+        points: &[GenericPointStruct<M>],
+        metadata_ids: &[Option<String>],
+        namespace: Option<&str>,
+    ) -> Result<Vec<(String, String)>, VectorStoreError> {
+        let mut conn = self.conn.clone();
+        let mut pipe = redis::pipe();
+        let ids = points
+            .iter()
+            .zip(metadata_ids)
+            .filter(|(point, _)| !self.skips(point))
+            .map(|(point, metadata_id)| self.queue_point(&mut pipe, point, namespace, metadata_id.as_deref()))
+            .collect::<Result<Vec<_>, _>>()?;
+        if ids.is_empty() {
+            return Ok(ids);
+        }
+        pipe.query_async::<()>(&mut conn).await?;
+
+        Ok(ids)
+    }
+
+    /// Whether `point` is left out under [`DimensionPolicy::Skip`]; logs a warning if so.
+    pub(crate) fn skips<M>(&self, point: &GenericPointStruct<M>) -> bool {
+        if self.dimension_policy != DimensionPolicy::Skip {
+            return false;
+        }
+        let Some(len) = std::iter::once(&point.vector)
+            .chain(point.named_vectors.values())
+            .map(Vec::len)
+            .find(|&len| len != self.vector_dim)
+        else {
+            return false;
+        };
+        log::warn!(
+            "Skipping point {}: {} dimensions, collection {} expects {}",
+            point.id,
+            len,
+            self.collection_name,
+            self.vector_dim
+        );
+        true
+    }
+
+    /// Queue the HSET for the vector hash and the JSON.SET for its payload.
+    /// Every reply is ignored.
+    fn queue_point<M: Serialize>(
+        &self,
+        pipe: &mut redis::Pipeline,
+        point: &GenericPointStruct<M>,
+        namespace: Option<&str>,
+        metadata_id: Option<&str>,
+    ) -> Result<(String, String), VectorStoreError> {
+        let vector = self.conform_vector(&point.id, &point.vector)?;
+        let named_vectors = self.named_vectors_of(point)?;
+
+        let vector_id = point.id.clone();
+        let vector_key = self.vector_key(&vector_id);
+        if self.storage == StorageMode::Json {
+            self.queue_json_point(pipe, &vector_key, &vector, point, namespace)?;
+            if !named_vectors.is_empty() {
+                pipe.cmd("JSON.SET").arg(&vector_key).arg("$.named_vectors").arg(serde_json::to_string(&named_vectors)?).ignore();
+            }
+            return Ok((vector_id, vector_key));
+        }
+        // Keep an existing point's document, e.g. `metadata:{collection}:{id}` after `copy_to`
+        let metadata_id = metadata_id.map_or_else(|| format!("metadata:{}", vector_id), str::to_string);
+        let payload = serde_json::to_value(&point.payload)?;
+
+        let vector_bytes = Self::serialize_vector(&vector);
+
+        let mut hash_map: HashMap<String, Vec<u8>> = HashMap::new();
+        hash_map.insert("vector".to_string(), vector_bytes);
+        for (name, vector) in &named_vectors {
+            hash_map.insert(name.to_string(), Self::serialize_vector(vector));
+        }
+        hash_map.insert("metadata_json_id".to_string(), metadata_id.clone().into_bytes());
+        if let Some(source) = payload_source(&payload) {
+            hash_map.insert("source".to_string(), source.as_bytes().to_vec());
+        }
+        if let Some(content) = payload["content"].as_str().filter(|_| self.index_content) {
+            hash_map.insert("content".to_string(), content.as_bytes().to_vec());
+        }
+        if let Some(ns) = namespace {
+            hash_map.insert("namespace".to_string(), ns.to_string().into_bytes());
+        }
+
+        let now = now_millis();
+        hash_map.insert("updated_at".to_string(), now.to_string().into_bytes());
+
+        let hash_vec: Vec<(String, Vec<u8>)> = hash_map.into_iter().collect();
+        pipe.cmd("HSET").arg(&vector_key).arg(&hash_vec).ignore();
+        // Only the first write sets created_at
+        pipe.cmd("HSETNX").arg(&vector_key).arg("created_at").arg(now).ignore();
+        if self.soft_delete {
+            // Re-upserting a tombstoned point revives it
+            pipe.cmd("HDEL").arg(&vector_key).arg("deleted").ignore();
+        }
+
+        pipe.cmd("JSON.SET").arg(&metadata_id).arg("$").arg(payload.to_string()).ignore();
+
+        if let Some(ttl) = self.ttl {
+            let millis = ttl.as_millis() as u64;
+            pipe.cmd("PEXPIRE").arg(&vector_key).arg(millis).ignore();
+            pipe.cmd("PEXPIRE").arg(&metadata_id).arg(millis).ignore();
+        }
+
+        Ok((vector_id, metadata_id))
+    }
+
+    /// A point's named vectors, resized by the dimension policy. Errors on a field the
+    /// collection does not declare.
+    fn named_vectors_of<'a, M>(&self, point: &'a GenericPointStruct<M>) -> Result<HashMap<&'a str, Vec<f64>>, VectorStoreError> {
+        point
+            .named_vectors
+            .iter()
+            .map(|(name, vector)| {
+                if !self.vector_fields.contains(name) {
+                    return Err(VectorStoreError::Other(format!(
+                        "Collection {} has no vector field {} (CollectionConfig::vector_field)",
+                        self.collection_name, name
+                    )));
+                }
+                Ok((name.as_str(), self.conform_vector(&point.id, vector)?.into_owned()))
+            })
+            .collect()
+    }
+
+    /// A vector about to be stored, resized by the dimension policy and checked for `NaN` and
+    /// infinite components: an error, or zeroed with [`with_sanitize_non_finite`](Self::with_sanitize_non_finite).
+    fn conform_vector<'a>(&self, id: &str, vector: &'a [f64]) -> Result<Cow<'a, [f64]>, VectorStoreError> {
+        let vector = self.dimension_policy.apply(vector, self.vector_dim)?;
+        let Some(index) = vector.iter().position(|x| !x.is_finite()) else {
+            return Ok(vector);
+        };
+        if !self.sanitize_non_finite {
+            return Err(VectorStoreError::Other(format!(
+                "Vector {} has a non-finite component ({} at index {})",
+                id, vector[index], index
+            )));
+        }
+        log::warn!("Replacing non-finite components of vector {} with 0.0", id);
+        Ok(Cow::Owned(vector.iter().map(|&x| if x.is_finite() { x } else { 0.0 }).collect()))
+    }
+
+    /// The declared named vectors present in a vector hash.
+    fn parse_named_vectors(&self, hash: &HashMap<String, Vec<u8>>) -> HashMap<String, Vec<f64>> {
+        self.vector_fields
+            .iter()
+            .filter_map(|name| Some((name.clone(), Self::deserialize_vector(hash.get(name)?))))
+            .collect()
+    }
+
+    /// Queue the field-by-field writes of a JSON collection document.
+    ///
+    /// The document is created empty if missing so `created_at` can be set only on the first
+    /// write (`NX` on the path); the other fields are then overwritten in place.
+    fn queue_json_point<M: Serialize>(
+        &self,
+        pipe: &mut redis::Pipeline,
+        key: &str,
+        vector: &[f64],
+        point: &GenericPointStruct<M>,
+        namespace: Option<&str>,
+    ) -> Result<(), VectorStoreError> {
+        let now = now_millis();
+        pipe.cmd("JSON.SET").arg(key).arg("$").arg("{}").arg("NX").ignore();
+        pipe.cmd("JSON.SET").arg(key).arg("$.created_at").arg(now).arg("NX").ignore();
+        pipe.cmd("JSON.SET").arg(key).arg("$.vector").arg(serde_json::to_string(vector)?).ignore();
+        pipe.cmd("JSON.SET").arg(key).arg("$.payload").arg(serde_json::to_string(&point.payload)?).ignore();
+        pipe.cmd("JSON.SET").arg(key).arg("$.updated_at").arg(now).ignore();
+        if let Some(ns) = namespace {
+            pipe.cmd("JSON.SET").arg(key).arg("$.namespace").arg(serde_json::to_string(ns)?).ignore();
+        }
+        if self.soft_delete {
+            pipe.cmd("JSON.DEL").arg(key).arg("$.deleted").ignore();
+        }
+        if let Some(ttl) = self.ttl {
+            pipe.cmd("PEXPIRE").arg(key).arg(ttl.as_millis() as u64).ignore();
+        }
+        Ok(())
+    }
+
+    /// Replace a vector's payload without re-sending the vector.
+    ///
+    /// Rewrites the JSON document referenced by `metadata_json_id`, the mirrored `source`
+    /// field and `updated_at`; the vector bytes are left as they are. Errors if the vector does not exist.
+    pub async fn update_metadata<M: Serialize>(&self, vector_id: &str, payload: &Payload<M>) -> Result<(), VectorStoreError> {
+        let mut conn = self.conn.clone();
+        let vector_key = self.vector_key(vector_id);
+        let (payload_key, payload_path) = self.payload_location(vector_id).await?;
+
+        let payload = serde_json::to_value(payload)?;
+        let mut pipe = redis::pipe();
+        pipe.cmd("JSON.SET").arg(&payload_key).arg(payload_path).arg(payload.to_string()).ignore();
+        self.queue_touch(&mut pipe, &vector_key, &payload);
+        pipe.query_async::<()>(&mut conn).await?;
+
+        Ok(())
+    }
+
+    /// Merge a partial JSON document into a vector's payload with `JSON.MERGE <id> $ <patch>`.
+    ///
+    /// Follows RFC 7396 merge-patch semantics: keys in `patch` are added or replaced, keys set
+    /// to `null` are removed and everything else is preserved, e.g.
+    /// `{"metadata": {"chunk_id": 3}}` bumps only the chunk ID.
+    /// Requires RedisJSON 2.6 or newer. Errors if the vector does not exist.
+    pub async fn merge_metadata(&self, vector_id: &str, patch: serde_json::Value) -> Result<(), VectorStoreError> {
+        let mut conn = self.conn.clone();
+        let (payload_key, payload_path) = self.payload_location(vector_id).await?;
+
+        let vector_key = self.vector_key(vector_id);
+        let mut pipe = redis::pipe();
+        pipe.cmd("JSON.MERGE").arg(&payload_key).arg(payload_path).arg(serde_json::to_string(&patch)?).ignore();
+        self.queue_touch(&mut pipe, &vector_key, &patch);
+        pipe.query_async::<()>(&mut conn).await?;
+
+        Ok(())
+    }
+
+    /// Set (`Some`) or remove (`None`) the expiration of a stored vector and its metadata.
+    /// Errors if the vector does not exist.
+    pub async fn set_ttl(&self, vector_id: &str, ttl: Option<Duration>) -> Result<(), VectorStoreError> {
+        let mut conn = self.conn.clone();
+        let vector_key = self.vector_key(vector_id);
+        let (payload_key, _) = self.payload_location(vector_id).await?;
+        let mut keys = vec![&vector_key];
+        if payload_key != vector_key {
+            keys.push(&payload_key);
+        }
+
+        let mut pipe = redis::pipe();
+        for key in keys {
+            match ttl {
+                Some(ttl) => pipe.cmd("PEXPIRE").arg(key).arg(ttl.as_millis() as u64).ignore(),
+                None => pipe.cmd("PERSIST").arg(key).ignore(),
+            };
+        }
+        pipe.query_async::<()>(&mut conn).await?;
+
+        Ok(())
+    }
+
+    /// Key of a point in this collection.
+    fn vector_key(&self, vector_id: &str) -> String {
+        format!("{}:{}", self.collection_name, vector_id)
+    }
+
+    /// Queue the `updated_at` bump after a change to `payload` (a full payload or a patch), plus
+    /// the mirrored `source` and `content` fields for hash collections (JSON collections index
+    /// the payload directly).
+    fn queue_touch(&self, pipe: &mut redis::Pipeline, vector_key: &str, payload: &serde_json::Value) {
+        let now = now_millis();
+        match self.storage {
+            StorageMode::Hash => {
+                pipe.cmd("HSET").arg(vector_key).arg("updated_at").arg(now).ignore();
+                if let Some(source) = payload_source(payload) {
+                    pipe.cmd("HSET").arg(vector_key).arg("source").arg(source).ignore();
+                }
+                if let Some(content) = payload["content"].as_str().filter(|_| self.index_content) {
+                    pipe.cmd("HSET").arg(vector_key).arg("content").arg(content).ignore();
+                }
+            }
+            StorageMode::Json => {
+                pipe.cmd("JSON.SET").arg(vector_key).arg("$.updated_at").arg(now).ignore();
+            }
+        }
+    }
+
+    /// Key and JSON path of a vector's payload: the separate `metadata:{id}` document for hash
+    /// collections, `$.payload` of the point itself for JSON collections.
+    /// Errors with `NotFound` if the vector does not exist.
+    async fn payload_location(&self, vector_id: &str) -> Result<(String, &'static str), VectorStoreError> {
+        match self.storage {
+            StorageMode::Hash => Ok((self.metadata_id_of(vector_id).await?, "$")),
+            StorageMode::Json if self.exists(vector_id).await? => Ok((self.vector_key(vector_id), "$.payload")),
+            StorageMode::Json => Err(VectorStoreError::NotFound { kind: "vector", id: vector_id.to_string() }),
+        }
+    }
+
+    /// Look up the key of the JSON payload document referenced by a vector hash.
+    async fn metadata_id_of(&self, vector_id: &str) -> Result<String, VectorStoreError> {
+        let mut conn = self.conn.clone();
+        let metadata_id: Option<String> = redis::cmd("HGET")
+            .arg(format!("{}:{}", self.collection_name, vector_id))
+            .arg("metadata_json_id")
+            .query_async(&mut conn)
+            .await?;
+        metadata_id.ok_or_else(|| VectorStoreError::NotFound { kind: "vector", id: vector_id.to_string() })
+    }
+
+    /// The payload document keys upserts should keep for `vector_ids`: looked up with
+    /// [`metadata_ids_of`](Self::metadata_ids_of) only in collections created by
+    /// [`copy_to`](Self::copy_to), whose points have their own payload documents; all `None`
+    /// (`metadata:{id}`) elsewhere, without a round-trip. Whether the collection is a copy is
+    /// checked once per engine.
+    async fn existing_metadata_ids(&self, vector_ids: &[String]) -> Result<Vec<Option<String>>, VectorStoreError> {
+        if self.storage == StorageMode::Json {
+            return Ok(vec![None; vector_ids.len()]);
+        }
+        let copied = self
+            .copied
+            .get_or_try_init(|| async {
+                let mut conn = self.conn.clone();
+                redis::cmd("EXISTS").arg(self.copied_key()).query_async::<bool>(&mut conn).await
+            })
+            .await?;
+        if *copied {
+            self.metadata_ids_of(vector_ids).await
+        } else {
+            Ok(vec![None; vector_ids.len()])
+        }
+    }
+
+    /// The payload document keys of hash points, with one pipelined `HGET metadata_json_id`;
+    /// `metadata:{id}` for points without one. All `None` for JSON collections, whose
+    /// payloads are stored inline, and for keys that turn out not to be hashes (a JSON
+    /// collection opened with the default config).
+    async fn metadata_ids_of(&self, vector_ids: &[String]) -> Result<Vec<Option<String>>, VectorStoreError> {
+        if self.storage == StorageMode::Json || vector_ids.is_empty() {
+            return Ok(vec![None; vector_ids.len()]);
+        }
+        let mut conn = self.conn.clone();
+        let mut pipe = redis::pipe();
+        pipe.ignore_errors();
+        for id in vector_ids {
+            pipe.cmd("HGET").arg(self.vector_key(id)).arg("metadata_json_id");
+        }
+        let metadata_ids: Vec<RedisResult<Option<String>>> = pipe.query_async(&mut conn).await?;
+        Ok(vector_ids
+            .iter()
+            .zip(metadata_ids)
+            .map(|(id, metadata_id)| metadata_id.ok().map(|metadata_id| metadata_id.unwrap_or_else(|| format!("metadata:{}", id))))
+            .collect())
+    }
+
+    /// Delete many points and their payload documents: one pipelined lookup of the documents,
+    /// then one pipeline of `DEL`s. Missing points are ignored.
+    pub async fn delete_vectors(&self, vector_ids: &[String]) -> Result<(), VectorStoreError> {
+        if vector_ids.is_empty() {
+            return Ok(());
+        }
+        let metadata_ids = self.metadata_ids_of(vector_ids).await?;
+        let mut pipe = redis::pipe();
+        for (id, metadata_id) in vector_ids.iter().zip(metadata_ids) {
+            pipe.cmd("DEL").arg(self.vector_key(id)).ignore();
+            if let Some(metadata_id) = metadata_id {
+                pipe.cmd("DEL").arg(metadata_id).ignore();
+            }
+        }
+        let mut conn = self.conn.clone();
+        pipe.query_async::<()>(&mut conn).await?;
+        Ok(())
+    }
+
+    pub async fn delete_vector_and_metadata(&self, vector_id: &str) -> Result<(), VectorStoreError> {
+        let mut conn = self.conn.clone();
+
+        let metadata_id = self.metadata_ids_of(&[vector_id.to_string()]).await?.pop().flatten();
+        let vector_key = self.vector_key(vector_id);
+        let _: () = redis::cmd("DEL")
+            .arg(&vector_key)
+            .query_async(&mut conn)
+            .await?;
+        let Some(metadata_id) = metadata_id else {
+            return Ok(());
+        };
+
+        let _: RedisResult<()> = redis::cmd("JSON.DEL")
+            .arg(&metadata_id)
+            .arg("$")
+            .query_async(&mut conn)
+            .await;
+
+        Ok(())
+    }
+
+    /// Tombstone a point instead of deleting it: tag it `deleted` so searches, counts and
+    /// aggregations skip it, while lookups by ID still return it. Requires a collection
+    /// created with [`CollectionConfig::soft_delete`]; [`purge_deleted`](Self::purge_deleted)
+    /// removes tombstoned points for good. Errors with `NotFound` if the vector does not exist.
+    pub async fn soft_delete(&self, vector_id: &str) -> Result<(), VectorStoreError> {
+        self.require_soft_delete()?;
+        if !self.exists(vector_id).await? {
+            return Err(VectorStoreError::NotFound { kind: "vector", id: vector_id.to_string() });
+        }
+
+        let mut conn = self.conn.clone();
+        let vector_key = self.vector_key(vector_id);
+        match self.storage {
+            StorageMode::Hash => redis::cmd("HSET").arg(&vector_key).arg("deleted").arg("1").query_async::<()>(&mut conn).await?,
+            StorageMode::Json => redis::cmd("JSON.SET").arg(&vector_key).arg("$.deleted").arg("\"1\"").query_async::<()>(&mut conn).await?,
+        }
+        Ok(())
+    }
+
+    /// Physically delete every soft-deleted point (and its metadata). Returns the number purged.
+    pub async fn purge_deleted(&self) -> Result<usize, VectorStoreError> {
+        self.require_soft_delete()?;
+        let mut conn = self.conn.clone();
+        let mut purged = 0;
+
+        // Each page is deleted before the next search, so always read from offset 0
+        loop {
+            let result: redis::Value = redis::cmd("FT.SEARCH")
+                .arg(&self.collection_name)
+                .arg("@deleted:{1}")
+                .arg("NOCONTENT")
+                .arg("LIMIT")
+                .arg("0")
+                .arg(RANGE_PAGE_SIZE)
+                .arg("DIALECT")
+                .arg(self.dialect)
+                .query_async(&mut conn)
+                .await?;
+
+            let prefix = format!("{}:", self.collection_name);
+            let ids: Vec<String> = match result {
+                redis::Value::Array(items) => items
+                    .iter()
+                    .skip(1)
+                    .filter_map(value_to_string)
+                    .map(|key| key.strip_prefix(&prefix).map(String::from).unwrap_or(key))
+                    .collect(),
+                _ => Vec::new(),
+            };
+            if ids.is_empty() {
+                break;
+            }
+
+            for id in &ids {
+                self.delete_vector_and_metadata(id).await?;
+            }
+            purged += ids.len();
+            if ids.len() < RANGE_PAGE_SIZE {
+                break;
+            }
+        }
+
+        Ok(purged)
+    }
+
+    fn require_soft_delete(&self) -> Result<(), VectorStoreError> {
+        if self.soft_delete {
+            Ok(())
+        } else {
+            Err(VectorStoreError::Other(format!(
+                "Collection {} is not configured for soft delete (CollectionConfig::soft_delete)",
+                self.collection_name
+            )))
+        }
+    }
+
+    /// A search query matching `clause` (every document when `None`), minus tombstoned points
+    /// in soft-delete collections.
+    fn live_query(&self, clause: Option<&str>) -> String {
+        match (clause, self.soft_delete) {
+            (Some(clause), true) => format!("{} -@deleted:{{1}}", clause),
+            (Some(clause), false) => clause.to_string(),
+            (None, true) => "-@deleted:{1}".to_string(),
+            (None, false) => "*".to_string(),
+        }
+    }
+
+    /// The pre-filter of a KNN or range search: the namespace union and the raw filter, minus
+    /// tombstoned points.
+    fn search_filter(&self, namespaces: &[&str]) -> String {
+        let clause = match (namespace_clause(namespaces), &self.raw_filter) {
+            (Some(namespaces), Some(raw)) => Some(format!("{} ({})", namespaces, raw)),
+            (None, Some(raw)) => Some(format!("({})", raw)),
+            (namespaces, None) => namespaces,
+        };
+        self.live_query(clause.as_deref())
+    }
+
+    /// Count the distinct values of an indexed field, e.g. the number of unique sources.
+    ///
+    /// `filter` is an optional RediSearch query (such as `@namespace:{docs}`) restricting
+    /// which documents are grouped; `None` considers the whole collection. Escape tag values
+    /// with [`escape_tag_value`].
+    /// Runs `FT.AGGREGATE ... GROUPBY 1 @field` and counts the returned groups.
+    pub async fn distinct_count(&self, field: &str, filter: Option<&str>) -> Result<usize, VectorStoreError> {
+        Ok(self.group_values(field, filter).await?.len())
+    }
+
+    /// Count the vectors per value of an indexed `TAG` field, e.g. how many vectors each
+    /// `source` holds. Sorted by count (largest first), then value; documents without the
+    /// field are not counted.
+    ///
+    /// Runs `FT.AGGREGATE collection * GROUPBY 1 @field REDUCE COUNT 0 AS cnt`.
+    pub async fn aggregate_count_by(&self, field: &str) -> Result<Vec<(String, usize)>, VectorStoreError> {
+        let mut conn = self.conn.clone();
+        let result: redis::Value = redis::cmd("FT.AGGREGATE")
+            .arg(&self.collection_name)
+            .arg(self.live_query(None))
+            .arg("GROUPBY")
+            .arg("1")
+            .arg(format!("@{}", field))
+            .arg("REDUCE")
+            .arg("COUNT")
+            .arg("0")
+            .arg("AS")
+            .arg("cnt")
+            .arg("DIALECT")
+            .arg(self.dialect)
+            .query_async(&mut conn)
+            .await?;
+
+        let items = match result {
+            redis::Value::Array(items) => items,
+            _ => return Ok(Vec::new()),
+        };
+
+        // First element is the total; each following row is [field, value, "cnt", count]
+        let mut counts: Vec<(String, usize)> = items
+            .iter()
+            .skip(1)
+            .filter_map(|row| {
+                let redis::Value::Array(fields) = row else { return None };
+                let mut value = None;
+                let mut count = None;
+                for pair in fields.chunks(2) {
+                    let [name, v] = pair else { continue };
+                    match value_to_string(name).as_deref() {
+                        Some("cnt") => count = value_to_f64(v),
+                        Some(name) if name == field => value = value_to_string(v),
+                        _ => {}
+                    }
+                }
+                Some((value?, count? as usize))
+            })
+            .collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        Ok(counts)
+    }
+
+    /// List the namespaces that currently hold at least one vector, sorted.
+    pub async fn list_namespaces(&self) -> Result<Vec<String>, VectorStoreError> {
+        let mut namespaces = self.group_values("namespace", None).await?;
+        namespaces.sort();
+        Ok(namespaces)
+    }
+
+    /// Count the vectors stored in a namespace with `FT.SEARCH ... LIMIT 0 0`.
+    pub async fn count_by_namespace(&self, namespace: &str) -> Result<usize, VectorStoreError> {
+        let mut conn = self.conn.clone();
+        let result: redis::Value = redis::cmd("FT.SEARCH")
+            .arg(&self.collection_name)
+            .arg(self.live_query(namespace_clause(&[namespace]).as_deref()))
+            .arg("LIMIT")
+            .arg("0")
+            .arg("0")
+            .arg("DIALECT")
+            .arg(self.dialect)
+            .query_async(&mut conn)
+            .await?;
+        Ok(parse_total(&result) as usize)
+    }
+
+    /// Distinct non-null values of `field` via `FT.AGGREGATE ... GROUPBY 1 @field`.
+    async fn group_values(&self, field: &str, filter: Option<&str>) -> Result<Vec<String>, VectorStoreError> {
+        let mut conn = self.conn.clone();
+
+        let query = match filter {
+            Some(f) if !f.is_empty() => self.live_query(Some(&format!("({})", f))),
+            _ => self.live_query(None),
+        };
+
+        let result: redis::Value = redis::cmd("FT.AGGREGATE")
+            .arg(&self.collection_name)
+            .arg(query)
+            .arg("GROUPBY")
+            .arg("1")
+            .arg(format!("@{}", field))
+            .arg("DIALECT")
+            .arg(self.dialect)
+            .query_async(&mut conn)
+            .await?;
+
+        let items = match result {
+            redis::Value::Array(items) => items,
+            _ => return Ok(Vec::new()),
+        };
+
+        // First element is the total; each following row is [field, value].
+        // Documents missing the field are grouped under a null value, which is skipped.
+        let values = items
+            .iter()
+            .skip(1)
+            .filter_map(|row| match row {
+                redis::Value::Array(fields) => fields.get(1).and_then(value_to_string),
+                _ => None,
+            })
+            .collect();
+
+        Ok(values)
+    }
+
+    /// Execute a KNN vector search query.
+    /// Returns (id, score, metadata_json_id) tuples for efficient batch metadata loading.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(command = "FT.SEARCH", collection = %self.collection_name, count)))]
+    pub async fn search_knn(
+        &self,
+        query_vector: &[f64],
+        count: usize,
+        namespace_filter: Option<&str>,
+    ) -> Result<Vec<(String, f64, String)>, VectorStoreError> {
+        self.search_knn_in(query_vector, count, namespace_filter.as_slice()).await
+    }
+
+    /// Like [`search_knn`](Self::search_knn), matching points in any of `namespaces` (every
+    /// point when empty). Hits are still ranked together, as one KNN query.
+    pub async fn search_knn_in(
+        &self,
+        query_vector: &[f64],
+        count: usize,
+        namespaces: &[&str],
+    ) -> Result<Vec<(String, f64, String)>, VectorStoreError> {
+        let mut conn = self.conn.clone();
+        let cmd = self.knn_cmd(query_vector, count, namespaces, &[]);
+        let result: redis::Value = self.await_search(cmd.query_async(&mut conn)).await?;
+
+        self.parse_knn_results(result)
+    }
+
+    /// Like [`search_knn`](Self::search_knn), but returns the requested fields from the search
+    /// reply itself instead of a key for a second metadata fetch.
+    ///
+    /// Returns `(id, score, vector, fields)`; `vector` is empty unless `include_vectors` is set
+    /// and `fields` is a JSON object of the fields present on each hit. Hash collections
+    /// return fields stored on the vector hash (`source`, `namespace`, `created_at`,
+    /// `updated_at`) as strings; other names are loaded from the payload document (`content`,
+    /// or under `metadata`, e.g. `chunk_id`) with one more round-trip. JSON collections resolve plain names under
+    /// `$.payload.metadata` (`$.`-prefixed paths are used as given) and parse values as JSON.
+    pub async fn search_knn_fields(
+        &self,
+        query_vector: &[f64],
+        count: usize,
+        namespace_filter: Option<&str>,
+        fields: &[String],
+        include_vectors: bool,
+    ) -> Result<Vec<(String, f64, Vec<f64>, serde_json::Value)>, VectorStoreError> {
+        self.search_knn_fields_in(query_vector, count, namespace_filter.as_slice(), fields, include_vectors).await
+    }
 
-        let index_exists: RedisResult<redis::Value> = redis::cmd("FT.INFO")
-            .arg(&self.collection_name)
-            .query_async(&mut conn)
-            .await;
+    /// Like [`search_knn_fields`](Self::search_knn_fields), matching points in any of
+    /// `namespaces` (every point when empty).
+    pub async fn search_knn_fields_in(
+        &self,
+        query_vector: &[f64],
+        count: usize,
+        namespaces: &[&str],
+        fields: &[String],
+        include_vectors: bool,
+    ) -> Result<Vec<(String, f64, Vec<f64>, serde_json::Value)>, VectorStoreError> {
+        let mut returned: Vec<String> = fields.to_vec();
+        if include_vectors {
+            returned.push("vector".to_string());
+        }
 
-        result.insert("index_exists".to_string(), serde_json::Value::Bool(index_exists.is_ok()));
+        let mut conn = self.conn.clone();
+        let cmd = self.knn_cmd(query_vector, count, namespaces, &returned);
+        let result: redis::Value = self.await_search(cmd.query_async(&mut conn)).await?;
 
-        let document_count = if let Ok(search_result) = redis::cmd("FT.SEARCH")
-            .arg(&self.collection_name)
-            .arg("*")
-            .arg("LIMIT")
-            .arg("0")
-            .arg("0")
-            .query_async::<redis::Value>(&mut conn)
-            .await
-        {
-            match search_result {
-                redis::Value::Int(count) => count,
-                redis::Value::Array(ref items) if !items.is_empty() => {
-                    match &items[0] {
-                        redis::Value::Int(count) => *count,
-                        _ => 0,
+        let mut hits = Vec::new();
+        let mut metadata_ids = Vec::new();
+        for (id, pairs) in self.parse_search_hits(result) {
+            let mut score = f64::NAN;
+            let mut vector = Vec::new();
+            let mut metadata_id = None;
+            let mut values = serde_json::Map::new();
+            for (name, value) in pairs {
+                match name.as_str() {
+                    "vector_score" => score = value_to_f64(&value).unwrap_or(f64::NAN),
+                    "metadata_json_id" => metadata_id = value_to_string(&value),
+                    "vector" if include_vectors => vector = self.parse_returned_vector(&value),
+                    _ => {
+                        values.insert(name, self.parse_returned_field(&value));
                     }
-                },
-                _ => 0,
+                }
             }
-        } else {
-            0
-        };
+            if !score.is_nan() {
+                hits.push((id, score, vector, serde_json::Value::Object(values)));
+                metadata_ids.push(metadata_id);
+            }
+        }
 
-        result.insert("document_count".to_string(), serde_json::Value::Number(serde_json::Number::from(document_count)));
-        Ok(result)
+        if self.storage == StorageMode::Hash {
+            self.load_payload_fields(&mut hits, &metadata_ids, fields).await?;
+        }
+        Ok(hits)
     }
 
-    pub async fn add_vector_and_metadata(&self, point: &PointStruct, namespace: Option<&str>) -> Result<(String, String), VectorStoreError> {
-        self.create_collection().await?;
-
-        if point.vector.len() != self.vector_dim {
-            return Err(VectorStoreError::Other(format!(
-                "Vector dimension mismatch: expected {}, got {}",
-                self.vector_dim,
-                point.vector.len()
-            )));
+    /// Fill in the `fields` a hash search could not return from the vector hash, from each
+    /// hit's payload document: `content` from `$.content`, other names from `$.metadata`.
+    /// One pipelined `JSON.GET` per hit missing a field; `$.`-prefixed paths are left out.
+    async fn load_payload_fields(
+        &self,
+        hits: &mut [(String, f64, Vec<f64>, serde_json::Value)],
+        metadata_ids: &[Option<String>],
+        fields: &[String],
+    ) -> Result<(), VectorStoreError> {
+        let path = |field: &str| match field {
+            "content" => "$.content".to_string(),
+            _ => format!("$.metadata.{}", field),
+        };
+        let mut pipe = redis::pipe();
+        let mut pending = Vec::new();
+        for (i, ((_, _, _, values), metadata_id)) in hits.iter().zip(metadata_ids).enumerate() {
+            let Some(metadata_id) = metadata_id else { continue };
+            let missing: Vec<&String> = fields
+                .iter()
+                .filter(|field| !field.is_empty() && !field.starts_with('$') && values.get(field.as_str()).is_none())
+                .collect();
+            if missing.is_empty() {
+                continue;
+            }
+            let cmd = pipe.cmd("JSON.GET").arg(metadata_id);
+            for field in &missing {
+                cmd.arg(path(field));
+            }
+            pending.push((i, missing));
+        }
+        if pending.is_empty() {
+            return Ok(());
         }
 
         let mut conn = self.conn.clone();
-        let vector_id = point.id.clone();
-        let metadata_id = format!("metadata:{}", vector_id);
-        let vector_key = format!("{}:{}", self.collection_name, vector_id);
+        let replies: Vec<Option<String>> = pipe.query_async(&mut conn).await?;
+        for ((i, missing), reply) in pending.into_iter().zip(replies) {
+            let Some(reply) = reply else { continue };
+            let reply: serde_json::Value = serde_json::from_str(&reply)?;
+            let serde_json::Value::Object(values) = &mut hits[i].3 else { continue };
+            for field in missing {
+                // One path replies with its matches; several with an object keyed by path
+                let matches = match &reply {
+                    serde_json::Value::Array(_) => &reply,
+                    _ => &reply[path(field).as_str()],
+                };
+                if let Some(value) = matches.get(0) {
+                    values.insert(field.clone(), value.clone());
+                }
+            }
+        }
+        Ok(())
+    }
 
-        let vector_bytes = Self::serialize_vector(&point.vector);
+    /// KNN search returning each hit's full point, in the same shape as
+    /// [`get_vectors_batch`](Self::get_vectors_batch).
+    ///
+    /// JSON collections return the payload, timestamps and (if `include_vectors`) the vector
+    /// in the search reply itself, so this is a single round-trip. Hash collections keep the
+    /// payload in a separate document and fall back to `search_knn` + `get_vectors_batch`.
+    pub async fn search_knn_points(
+        &self,
+        query_vector: &[f64],
+        count: usize,
+        namespace_filter: Option<&str>,
+        include_vectors: bool,
+    ) -> Result<Vec<(String, f64, Option<PointStruct>)>, VectorStoreError> {
+        self.search_knn_points_in(query_vector, count, namespace_filter.as_slice(), include_vectors).await
+    }
 
-        let mut hash_map: HashMap<String, Vec<u8>> = HashMap::new();
-        hash_map.insert("vector".to_string(), vector_bytes);
-        hash_map.insert("metadata_json_id".to_string(), metadata_id.clone().into_bytes());
-        if let Some(ns) = namespace {
-            hash_map.insert("namespace".to_string(), ns.to_string().into_bytes());
+    /// Like [`search_knn_points`](Self::search_knn_points), matching points in any of
+    /// `namespaces` (every point when empty).
+    pub async fn search_knn_points_in(
+        &self,
+        query_vector: &[f64],
+        count: usize,
+        namespaces: &[&str],
+        include_vectors: bool,
+    ) -> Result<Vec<(String, f64, Option<PointStruct>)>, VectorStoreError> {
+        if self.storage == StorageMode::Hash {
+            let hits = self.search_knn_in(query_vector, count, namespaces).await?;
+            return self.get_vectors_batch(&hits, include_vectors).await;
         }
 
-        let hash_vec: Vec<(String, Vec<u8>)> = hash_map.into_iter().collect();
-        redis::cmd("HSET")
-            .arg(&vector_key)
-            .arg(&hash_vec)
-            .query_async::<()>(&mut conn)
+        let fields = ["$.payload", "$.created_at", "$.updated_at"].map(String::from);
+        let hits = self
+            .search_knn_fields_in(query_vector, count, namespaces, &fields, include_vectors)
             .await?;
 
-        let metadata_json = serde_json::to_string(&point.payload)?;
-        redis::cmd("JSON.SET")
-            .arg(&metadata_id)
-            .arg("$")
-            .arg(&metadata_json)
-            .query_async::<()>(&mut conn)
-            .await?;
+        Ok(hits
+            .into_iter()
+            .map(|(id, score, vector, mut fields)| {
+                let point = fields
+                    .get_mut("$.payload")
+                    .map(serde_json::Value::take)
+                    .and_then(|payload| serde_json::from_value::<Payload>(payload).ok())
+                    .map(|payload| PointStruct {
+                        created_at: fields.get("$.created_at").and_then(serde_json::Value::as_u64),
+                        updated_at: fields.get("$.updated_at").and_then(serde_json::Value::as_u64),
+                        ..PointStruct::new(&id, vector, payload)
+                    });
+                (id, score, point)
+            })
+            .collect())
+    }
 
-        Ok((vector_id, metadata_id))
+    /// A vector returned by `FT.SEARCH`: raw bytes for hashes, a JSON array for JSON documents.
+    fn parse_returned_vector(&self, value: &redis::Value) -> Vec<f64> {
+        match (self.storage, value) {
+            (StorageMode::Hash, redis::Value::BulkString(bytes)) => Self::deserialize_vector(bytes),
+            _ => value_to_string(value)
+                .and_then(|s| serde_json::from_str(&s).ok())
+                .unwrap_or_default(),
+        }
     }
 
-    pub async fn delete_vector_and_metadata(&self, vector_id: &str) -> Result<(), VectorStoreError> {
-        let mut conn = self.conn.clone();
+    /// A field returned by `FT.SEARCH`: JSON documents return JSON-encoded values, hashes plain strings.
+    fn parse_returned_field(&self, value: &redis::Value) -> serde_json::Value {
+        let Some(raw) = value_to_string(value) else {
+            return serde_json::Value::Null;
+        };
+        match self.storage {
+            StorageMode::Json => serde_json::from_str(&raw).unwrap_or(serde_json::Value::String(raw)),
+            StorageMode::Hash => serde_json::Value::String(raw),
+        }
+    }
 
-        let vector_key = format!("{}:{}", self.collection_name, vector_id);
-        let _: () = redis::cmd("DEL")
-            .arg(&vector_key)
-            .query_async(&mut conn)
-            .await?;
+    /// Run one KNN query per vector in a single pipelined round-trip.
+    /// Results are aligned with `query_vectors`.
+    pub async fn search_knn_batch(
+        &self,
+        query_vectors: &[Vec<f64>],
+        count: usize,
+        namespace_filter: Option<&str>,
+    ) -> Result<Vec<Vec<(String, f64, String)>>, VectorStoreError> {
+        if query_vectors.is_empty() {
+            return Ok(Vec::new());
+        }
 
-        let metadata_id = format!("metadata:{}", vector_id);
-        let _: RedisResult<()> = redis::cmd("JSON.DEL")
-            .arg(&metadata_id)
-            .arg("$")
-            .query_async(&mut conn)
-            .await;
+        let mut conn = self.conn.clone();
+        let mut pipe = redis::pipe();
+        for vector in query_vectors {
+            pipe.add_command(self.knn_cmd(vector, count, namespace_filter.as_slice(), &[]));
+        }
+        let results: Vec<redis::Value> = self.await_search(pipe.query_async(&mut conn)).await?;
 
-        Ok(())
+        results
+            .into_iter()
+            .map(|result| self.parse_knn_results(result))
+            .collect()
     }
 
-    /// Execute a KNN vector search query.
-    /// Returns (id, score, metadata_json_id) tuples for efficient batch metadata loading.
-    pub async fn search_knn(
+    /// Open a KNN query as a server-side cursor returning `page_size` hits per page, so large
+    /// `count`s can be consumed incrementally (see [`KnnCursor`]). Matches points in any of
+    /// `namespaces` (every point when empty).
+    ///
+    /// Runs `FT.AGGREGATE ... SORTBY 2 @vector_score ASC WITHCURSOR COUNT page_size`; the first
+    /// page comes back with the cursor.
+    ///
+    /// Not supported on a cluster, where `FT.CURSOR` reads could reach a node that does not
+    /// hold the cursor.
+    pub async fn search_knn_cursor(
         &self,
         query_vector: &[f64],
         count: usize,
-        namespace_filter: Option<&str>,
-    ) -> Result<Vec<(String, f64, String)>, VectorStoreError> {
+        namespaces: &[&str],
+        page_size: usize,
+    ) -> Result<KnnCursor, VectorStoreError> {
+        if self.conn.is_cluster() {
+            return Err(VectorStoreError::Other("query cursors are not supported on a cluster".to_string()));
+        }
+        let filter = self.search_filter(namespaces);
+        let knn_query = format!("({})=>[KNN $K @{} $vec AS vector_score]", filter, self.query_field);
+
+        let mut cmd = redis::cmd("FT.AGGREGATE");
+        cmd.arg(&self.collection_name)
+            .arg(&knn_query)
+            .arg("PARAMS")
+            .arg("4")
+            .arg("vec")
+            .arg(Self::serialize_vector(query_vector))
+            .arg("K")
+            .arg(count);
+        // JSON documents have no metadata_json_id; their payload is loaded by key
+        let load: &[&str] = match self.storage {
+            StorageMode::Hash => &["@__key", "@metadata_json_id"],
+            StorageMode::Json => &["@__key"],
+        };
+        cmd.arg("LOAD")
+            .arg(load.len())
+            .arg(load)
+            // Without MAX, SORTBY keeps only the first 10 rows
+            .arg("SORTBY")
+            .arg("2")
+            .arg("@vector_score")
+            .arg("ASC")
+            .arg("MAX")
+            .arg(count)
+            .arg("WITHCURSOR")
+            .arg("COUNT")
+            .arg(page_size);
+        self.push_timeout(&mut cmd);
+        cmd.arg("DIALECT").arg(self.dialect);
+
         let mut conn = self.conn.clone();
+        let reply: redis::Value = self.await_search(cmd.query_async(&mut conn)).await?;
+        let (page, id) = self.parse_cursor_reply(reply);
+        Ok(KnnCursor { engine: self.clone(), id, page_size, first_page: Some(page) })
+    }
 
-        let filter = match namespace_filter {
-            Some(ns) if !ns.is_empty() => format!("@namespace:{{{}}}", ns),
-            _ => "*".to_string(),
+    /// Split a `WITHCURSOR` reply, `[[total, row...], cursor_id]`, into its hits and the
+    /// cursor ID (0 once exhausted).
+    fn parse_cursor_reply(&self, reply: redis::Value) -> (Vec<(String, f64, String)>, u64) {
+        let redis::Value::Array(mut parts) = reply else { return (Vec::new(), 0) };
+        let id = parts.get(1).and_then(value_to_f64).unwrap_or(0.0) as u64;
+        let rows = match parts.swap_remove(0) {
+            redis::Value::Array(rows) => rows,
+            _ => Vec::new(),
         };
 
-        let knn_query = format!("({})=>[KNN $K @vector $vec AS vector_score]", filter);
+        let prefix = format!("{}:", self.collection_name);
+        let hits = rows
+            .iter()
+            .skip(1)
+            .filter_map(|row| {
+                let redis::Value::Array(fields) = row else { return None };
+                let (mut key, mut score, mut metadata_json_id) = (None, None, String::new());
+                for pair in fields.chunks(2) {
+                    let [name, value] = pair else { continue };
+                    match value_to_string(name).as_deref() {
+                        Some("__key") => key = value_to_string(value),
+                        Some("vector_score") => score = value_to_f64(value),
+                        Some("metadata_json_id") => metadata_json_id = value_to_string(value).unwrap_or_default(),
+                        _ => {}
+                    }
+                }
+                let key = key?;
+                let id = key.strip_prefix(&prefix).unwrap_or(&key).to_string();
+                Some((id, score?, metadata_json_id))
+            })
+            .collect();
+        (hits, id)
+    }
+
+    /// Build the `FT.SEARCH ... KNN` command shared by single and batched searches.
+    /// `return_fields` are returned after the score and metadata key (see [`search_knn_fields`](Self::search_knn_fields)).
+    fn knn_cmd(&self, query_vector: &[f64], count: usize, namespaces: &[&str], return_fields: &[String]) -> redis::Cmd {
+        let filter = self.search_filter(namespaces);
+        let knn_query = format!("({})=>[KNN $K @{} $vec AS vector_score]", filter, self.query_field);
 
         let vector_bytes = Self::serialize_vector(query_vector);
         let k_str = count.to_string();
 
-        let result: redis::Value = redis::cmd("FT.SEARCH")
-            .arg(&self.collection_name)
+        let mut cmd = redis::cmd("FT.SEARCH");
+        cmd.arg(&self.collection_name)
             .arg(&knn_query)
             .arg("PARAMS")
             .arg("4")
             .arg("vec")
             .arg(&vector_bytes)
             .arg("K")
-            .arg(&k_str)
-            .arg("RETURN")
-            .arg("2")
-            .arg("vector_score")
-            .arg("metadata_json_id")
+            .arg(&k_str);
+
+        let mut returned = vec!["vector_score".to_string(), "metadata_json_id".to_string()];
+        // An empty name would be sent as a bogus RETURN field and skew the count
+        for field in return_fields.iter().filter(|field| !field.is_empty()) {
+            match self.storage {
+                StorageMode::Hash => returned.push(field.clone()),
+                StorageMode::Json if field.starts_with('$') => returned.push(field.clone()),
+                StorageMode::Json => {
+                    let path = match field.as_str() {
+                        "vector" => "$.vector".to_string(),
+                        _ => format!("$.payload.metadata.{}", field),
+                    };
+                    returned.extend([path, "AS".to_string(), field.clone()]);
+                }
+            }
+        }
+        cmd.arg("RETURN")
+            .arg(returned.len())
+            .arg(&returned)
             .arg("SORTBY")
             .arg("vector_score")
             .arg("ASC")
             .arg("LIMIT")
             .arg("0")
-            .arg(&k_str)
-            .arg("DIALECT")
-            .arg("2")
-            .query_async(&mut conn)
-            .await?;
+            .arg(&k_str);
+        self.push_timeout(&mut cmd);
+        cmd.arg("DIALECT").arg(self.dialect);
+        cmd
+    }
 
-        self.parse_knn_results(result)
+    /// Append the query timeout to a search. Under [`OnTimeout::Fail`] the budget is enforced
+    /// by [`await_search`](Self::await_search) instead, and `TIMEOUT 0` keeps the server from
+    /// cutting the query short with partial results.
+    fn push_timeout(&self, cmd: &mut Cmd) {
+        if let Some(timeout) = self.query_timeout {
+            let millis = match self.on_timeout {
+                OnTimeout::Return => timeout.as_millis() as u64,
+                OnTimeout::Fail => 0,
+            };
+            cmd.arg("TIMEOUT").arg(millis);
+        }
     }
 
-    fn parse_knn_results(&self, value: redis::Value) -> Result<Vec<(String, f64, String)>, VectorStoreError> {
-        let items = match value {
-            redis::Value::Array(items) => items,
-            _ => return Ok(Vec::new()),
+    /// Await a search reply, mapping its errors with [`search_error`]. Under
+    /// [`OnTimeout::Fail`], a reply that takes longer than the query timeout is abandoned with
+    /// [`VectorStoreError::Timeout`].
+    async fn await_search<T>(&self, search: impl Future<Output = RedisResult<T>>) -> Result<T, VectorStoreError> {
+        let reply = match (self.query_timeout, self.on_timeout) {
+            (Some(timeout), OnTimeout::Fail) => tokio::time::timeout(timeout, search).await.map_err(|_| {
+                VectorStoreError::Timeout(format!("Search on {} exceeded {} ms", self.collection_name, timeout.as_millis()))
+            })?,
+            _ => search.await,
         };
+        reply.map_err(|err| search_error(err, &self.collection_name))
+    }
 
-        if items.len() < 2 {
-            return Ok(Vec::new());
-        }
+    /// Execute a vector range query: every point within `radius` (in the index's distance
+    /// metric) of `query_vector`, closest first. Returns the same tuples as [`search_knn`](Self::search_knn).
+    ///
+    /// Results are fetched in pages of 1000 until the range is exhausted.
+    pub async fn search_range(
+        &self,
+        query_vector: &[f64],
+        radius: f64,
+        namespace_filter: Option<&str>,
+    ) -> Result<Vec<(String, f64, String)>, VectorStoreError> {
+        let mut conn = self.conn.clone();
 
-        let mut results = Vec::new();
+        let range = format!("@{}:[VECTOR_RANGE $r $vec]=>{{$YIELD_DISTANCE_AS: vector_score}}", self.query_field);
+        let query = match self.search_filter(namespace_filter.as_slice()).as_str() {
+            "*" => range,
+            filter => format!("{} {}", filter, range),
+        };
+        let vector_bytes = Self::serialize_vector(query_vector);
 
-        for i in (1..items.len()).step_by(2) {
-            if i + 1 >= items.len() {
+        let mut results = Vec::new();
+        loop {
+            let mut cmd = redis::cmd("FT.SEARCH");
+            cmd.arg(&self.collection_name)
+                .arg(&query)
+                .arg("PARAMS")
+                .arg("4")
+                .arg("vec")
+                .arg(&vector_bytes)
+                .arg("r")
+                .arg(radius)
+                .arg("RETURN")
+                .arg("2")
+                .arg("vector_score")
+                .arg("metadata_json_id")
+                .arg("SORTBY")
+                .arg("vector_score")
+                .arg("ASC")
+                .arg("LIMIT")
+                .arg(results.len())
+                .arg(RANGE_PAGE_SIZE);
+            self.push_timeout(&mut cmd);
+            cmd.arg("DIALECT").arg(self.dialect);
+            let result: redis::Value = self.await_search(cmd.query_async(&mut conn)).await?;
+
+            let total = parse_total(&result) as usize;
+            let page = self.parse_knn_results(result)?;
+            let page_len = page.len();
+            results.extend(page);
+            if page_len < RANGE_PAGE_SIZE || results.len() >= total {
                 break;
             }
+        }
 
-            let doc_id = match &items[i] {
-                redis::Value::BulkString(bytes) => String::from_utf8_lossy(bytes).to_string(),
-                redis::Value::SimpleString(s) => s.clone(),
-                _ => continue,
-            };
-
-            let prefix = format!("{}:", self.collection_name);
-            let id = doc_id.strip_prefix(&prefix).unwrap_or(&doc_id).to_string();
-
-            let fields = match &items[i + 1] {
-                redis::Value::Array(field_values) => field_values,
-                _ => continue,
-            };
+        Ok(results)
+    }
 
-            let mut score = f64::NAN;
-            let mut metadata_json_id = String::new();
-            for j in (0..fields.len()).step_by(2) {
-                if j + 1 >= fields.len() {
-                    break;
-                }
-                let field_name = match &fields[j] {
-                    redis::Value::BulkString(bytes) => String::from_utf8_lossy(bytes).to_string(),
-                    redis::Value::SimpleString(s) => s.clone(),
-                    _ => continue,
-                };
-                match field_name.as_str() {
-                    "vector_score" => {
-                        score = match &fields[j + 1] {
-                            redis::Value::BulkString(bytes) => {
-                                String::from_utf8_lossy(bytes).parse::<f64>().unwrap_or(f64::NAN)
-                            },
-                            redis::Value::SimpleString(s) => s.parse::<f64>().unwrap_or(f64::NAN),
-                            redis::Value::Double(d) => *d,
-                            _ => f64::NAN,
-                        };
-                    }
-                    "metadata_json_id" => {
-                        metadata_json_id = match &fields[j + 1] {
-                            redis::Value::BulkString(bytes) => String::from_utf8_lossy(bytes).to_string(),
-                            redis::Value::SimpleString(s) => s.clone(),
-                            _ => String::new(),
-                        };
+    fn parse_knn_results(&self, value: redis::Value) -> Result<Vec<(String, f64, String)>, VectorStoreError> {
+        let results = self
+            .parse_search_hits(value)
+            .into_iter()
+            .filter_map(|(id, fields)| {
+                let mut score = f64::NAN;
+                let mut metadata_json_id = String::new();
+                for (name, value) in &fields {
+                    match name.as_str() {
+                        "vector_score" => score = value_to_f64(value).unwrap_or(f64::NAN),
+                        "metadata_json_id" => metadata_json_id = value_to_string(value).unwrap_or_default(),
+                        _ => {}
                     }
-                    _ => {}
                 }
-            }
+                (!score.is_nan()).then_some((id, score, metadata_json_id))
+            })
+            .collect();
 
-            if !score.is_nan() {
-                results.push((id, score, metadata_json_id));
+        Ok(results)
+    }
+
+    /// Split an `FT.SEARCH` reply into `(id, [(field, value)])` hits, with the collection
+    /// prefix stripped from each key.
+    fn parse_search_hits(&self, value: redis::Value) -> Vec<(String, Vec<(String, redis::Value)>)> {
+        let items = match value {
+            redis::Value::Array(items) => items,
+            _ => return Vec::new(),
+        };
+
+        let prefix = format!("{}:", self.collection_name);
+        let mut hits = Vec::new();
+        let mut items = items.into_iter().skip(1);
+        while let (Some(key), Some(fields)) = (items.next(), items.next()) {
+            let Some(doc_id) = value_to_string(&key) else { continue };
+            let id = doc_id.strip_prefix(&prefix).unwrap_or(&doc_id).to_string();
+
+            let redis::Value::Array(fields) = fields else { continue };
+            let mut pairs = Vec::with_capacity(fields.len() / 2);
+            let mut fields = fields.into_iter();
+            while let (Some(name), Some(value)) = (fields.next(), fields.next()) {
+                if let Some(name) = value_to_string(&name) {
+                    pairs.push((name, value));
+                }
             }
+            hits.push((id, pairs));
         }
-
-        Ok(results)
+        hits
     }
 
-    /// Batch-fetch vectors and their payloads by metadata_json_id.
-    /// Much more efficient than individual get_vector calls.
+    /// Batch-fetch vectors and their payloads: one pipelined `HGETALL` per hit, then the
+    /// payloads (by each hash's `metadata_json_id`) with [`get_metadata_batch`](Self::get_metadata_batch).
+    /// Hits whose point or payload document is gone come back as `None`.
     pub async fn get_vectors_batch(
         &self,
         ids_and_scores: &[(String, f64, String)],
         include_vectors: bool,
     ) -> Result<Vec<(String, f64, Option<PointStruct>)>, VectorStoreError> {
-        let mut conn = self.conn.clone();
-        let mut results = Vec::with_capacity(ids_and_scores.len());
+        if self.storage == StorageMode::Json {
+            let ids: Vec<String> = ids_and_scores.iter().map(|(id, _, _)| id.clone()).collect();
+            let points = self.get_vectors_json(&ids).await?;
+            return Ok(ids_and_scores
+                .iter()
+                .zip(points)
+                .map(|((id, score, _), point)| {
+                    let point = point.map(|mut p| {
+                        if !include_vectors {
+                            p.vector.clear();
+                            p.named_vectors.clear();
+                        }
+                        p
+                    });
+                    (id.clone(), *score, point)
+                })
+                .collect());
+        }
 
-        for (id, score, meta_id) in ids_and_scores {
-            let exists: bool = redis::cmd("EXISTS")
-                .arg(format!("{}:{}", self.collection_name, id))
-                .query_async(&mut conn)
-                .await?;
+        if ids_and_scores.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut conn = self.conn.clone();
+        let mut pipe = redis::pipe();
+        for (id, _, _) in ids_and_scores {
+            pipe.cmd("HGETALL").arg(self.vector_key(id));
+        }
+        // A missing key reads as an empty hash
+        let hashes: Vec<HashMap<String, Vec<u8>>> = pipe.query_async(&mut conn).await?;
+
+        // Payloads of the hits that still exist, in one JSON.MGET
+        let metadata_ids: Vec<String> = ids_and_scores
+            .iter()
+            .zip(&hashes)
+            .filter(|(_, hash)| hash.contains_key("vector"))
+            .map(|((_, _, meta_id), hash)| {
+                hash.get("metadata_json_id").map_or_else(|| meta_id.clone(), |id| String::from_utf8_lossy(id).into_owned())
+            })
+            .collect();
+        let mut payloads = self.get_metadata_batch_as(&metadata_ids).await?.into_iter();
 
-            if !exists {
+        let mut results = Vec::with_capacity(ids_and_scores.len());
+        for ((id, score, _), hash) in ids_and_scores.iter().zip(hashes) {
+            let Some(vector_bytes) = hash.get("vector") else {
                 results.push((id.clone(), *score, None));
                 continue;
-            }
-
-            let vector_data: HashMap<String, Vec<u8>> = redis::cmd("HGETALL")
-                .arg(format!("{}:{}", self.collection_name, id))
-                .query_async(&mut conn)
-                .await?;
-
-            let vector_bytes = match vector_data.get("vector") {
-                Some(b) => b,
-                None => {
-                    results.push((id.clone(), *score, None));
-                    continue;
-                }
             };
-
-            let vector = if include_vectors {
-                Self::deserialize_vector(vector_bytes)
-            } else {
-                Vec::new()
-            };
-
-            let metadata_json: String = redis::cmd("JSON.GET")
-                .arg(meta_id)
-                .query_async(&mut conn)
-                .await?;
-
-            let payload: crate::models::Payload = if metadata_json.trim_start().starts_with('[') {
-                let arr: Vec<crate::models::Payload> = serde_json::from_str(&metadata_json)?;
-                match arr.into_iter().next() {
-                    Some(p) => p,
-                    None => {
-                        results.push((id.clone(), *score, None));
-                        continue;
-                    }
-                }
-            } else {
-                serde_json::from_str(&metadata_json)?
+            // Missing payload documents read as None
+            let Some(payload) = payloads.next().flatten() else {
+                results.push((id.clone(), *score, None));
+                continue;
             };
 
+            let vector = if include_vectors { Self::deserialize_vector(vector_bytes) } else { Vec::new() };
+            let (created_at, updated_at) = parse_timestamps(&hash);
+            let named_vectors = if include_vectors { self.parse_named_vectors(&hash) } else { HashMap::new() };
             results.push((id.clone(), *score, Some(PointStruct {
                 id: id.clone(),
                 vector,
                 payload,
+                created_at,
+                updated_at,
+                named_vectors,
             })));
         }
 
@@ -465,6 +2751,211 @@ impl RedisEngine {
     }
 }
 
+/// A point as stored in a JSON collection. `namespace` is only needed by the index.
+#[derive(Deserialize)]
+struct JsonPoint<M> {
+    vector: Vec<f64>,
+    payload: Payload<M>,
+    #[serde(default)]
+    created_at: Option<u64>,
+    #[serde(default)]
+    updated_at: Option<u64>,
+    #[serde(default)]
+    named_vectors: HashMap<String, Vec<f64>>,
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// The payload's `metadata.source` string, mirrored into hashes for the `source` TAG field.
+fn payload_source(payload: &serde_json::Value) -> Option<&str> {
+    payload.pointer("/metadata/source").and_then(|v| v.as_str())
+}
+
+/// Read `(created_at, updated_at)` from a vector hash; absent for points written before
+/// timestamps were recorded.
+fn parse_timestamps(hash: &HashMap<String, Vec<u8>>) -> (Option<u64>, Option<u64>) {
+    let field = |name: &str| {
+        hash.get(name)
+            .and_then(|bytes| std::str::from_utf8(bytes).ok())
+            .and_then(|s| s.parse().ok())
+    };
+    (field("created_at"), field("updated_at"))
+}
+
+/// JSON.GET returns either an array (with `$` path) or a bare object (without `$`).
+/// An empty array yields `None`.
+fn parse_payload<M: DeserializeOwned>(metadata_json: &str) -> Result<Option<Payload<M>>, VectorStoreError> {
+    if metadata_json.trim_start().starts_with('[') {
+        let arr: Vec<Payload<M>> = serde_json::from_str(metadata_json)?;
+        Ok(arr.into_iter().next())
+    } else {
+        Ok(Some(serde_json::from_str(metadata_json)?))
+    }
+}
+
+/// Map a search failure on `collection` to [`VectorStoreError::Timeout`] when RediSearch gave
+/// up on the query (`ON_TIMEOUT FAIL`), to `NotFound { kind: "collection" }` when the index
+/// does not exist, and to a plain Redis error otherwise.
+fn search_error(err: redis::RedisError, collection: &str) -> VectorStoreError {
+    let message = err.to_string();
+    let lowercase = message.to_lowercase();
+    if message.contains("Timeout limit was reached") {
+        VectorStoreError::Timeout(message)
+    } else if lowercase.contains("no such index") || lowercase.contains("unknown index name") {
+        VectorStoreError::NotFound { kind: "collection", id: collection.to_string() }
+    } else {
+        VectorStoreError::Redis(err)
+    }
+}
+
+/// RediSearch clause restricting a query to any of `namespaces` as a tag union
+/// (`@namespace:{a | b}`); `None` when unfiltered. Empty names are ignored.
+fn namespace_clause(namespaces: &[&str]) -> Option<String> {
+    let namespaces: Vec<&str> = namespaces.iter().copied().filter(|ns| !ns.is_empty()).collect();
+    if namespaces.is_empty() {
+        None
+    } else {
+        Some(Filter::tag("namespace", namespaces).to_query_string())
+    }
+}
+
+/// Reject collection names containing `:`: the index and `SCAN` of `docs` cover every key
+/// under `docs:`, including those of a collection `docs:archive`.
+fn check_collection_name(name: &str) -> Result<(), VectorStoreError> {
+    if name.contains(':') {
+        return Err(VectorStoreError::Other(format!("Collection name {} must not contain ':'", name)));
+    }
+    Ok(())
+}
+
+/// Escape the glob characters `*?[]\` for a `SCAN ... MATCH` pattern.
+fn glob_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// The total-results count at the head of an `FT.SEARCH` reply.
+fn parse_total(value: &redis::Value) -> i64 {
+    match value {
+        redis::Value::Int(count) => *count,
+        redis::Value::Array(items) => match items.first() {
+            Some(redis::Value::Int(count)) => *count,
+            _ => 0,
+        },
+        _ => 0,
+    }
+}
+
+/// Module names from a `MODULE LIST` reply: one `name`/value pair per module, as a flat
+/// array (RESP2) or a map (RESP3).
+fn module_names(reply: &redis::Value) -> Vec<String> {
+    let redis::Value::Array(modules) = reply else {
+        return Vec::new();
+    };
+    modules
+        .iter()
+        .filter_map(|module| match module {
+            redis::Value::Array(fields) => fields
+                .chunks(2)
+                .find(|pair| value_to_string(&pair[0]).as_deref() == Some("name"))
+                .and_then(|pair| pair.get(1))
+                .and_then(value_to_string),
+            redis::Value::Map(fields) => fields
+                .iter()
+                .find(|(k, _)| value_to_string(k).as_deref() == Some("name"))
+                .and_then(|(_, v)| value_to_string(v)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Key/value pairs from an `FT.INFO` reply, given as a flat alternating array (RESP2) or a map (RESP3).
+fn info_fields(reply: redis::Value) -> HashMap<String, redis::Value> {
+    match reply {
+        redis::Value::Array(items) => {
+            let mut fields = HashMap::new();
+            let mut items = items.into_iter();
+            while let (Some(key), Some(value)) = (items.next(), items.next()) {
+                if let Some(key) = value_to_string(&key) {
+                    fields.insert(key, value);
+                }
+            }
+            fields
+        }
+        redis::Value::Map(pairs) => pairs
+            .into_iter()
+            .filter_map(|(k, v)| value_to_string(&k).map(|k| (k, v)))
+            .collect(),
+        _ => HashMap::new(),
+    }
+}
+
+/// The `dim` of the `VECTOR` entry in `FT.INFO`'s `attributes` list.
+fn vector_attribute_dim(attributes: &redis::Value) -> Option<usize> {
+    let redis::Value::Array(attributes) = attributes else {
+        return None;
+    };
+    attributes.iter().find_map(|attribute| {
+        let fields = info_fields(attribute.clone());
+        let is_vector = fields.get("type").and_then(value_to_string).is_some_and(|t| t.eq_ignore_ascii_case("VECTOR"));
+        if is_vector {
+            fields.get("dim").and_then(value_to_f64).map(|dim| dim as usize)
+        } else {
+            None
+        }
+    })
+}
+
+/// Numeric `FT.INFO` values come back as integers, doubles or strings depending on the field and protocol.
+fn value_to_f64(value: &redis::Value) -> Option<f64> {
+    match value {
+        redis::Value::Int(n) => Some(*n as f64),
+        redis::Value::Double(d) => Some(*d),
+        other => value_to_string(other).and_then(|s| s.parse().ok()),
+    }
+}
+
+fn value_to_string(value: &redis::Value) -> Option<String> {
+    match value {
+        redis::Value::BulkString(bytes) => Some(String::from_utf8_lossy(bytes).to_string()),
+        redis::Value::SimpleString(s) => Some(s.clone()),
+        _ => None,
+    }
+}
+
+/// Backslash-escape the characters RediSearch's query syntax treats specially (ASCII
+/// punctuation other than `_`, and whitespace), so `value` can be used inside a TAG filter
+/// such as `@namespace:{...}` and matches literally.
+///
+/// ```
+/// use redis_vector_store::escape_tag_value;
+///
+/// assert_eq!(escape_tag_value("tenant-1"), r"tenant\-1");
+/// assert_eq!(escape_tag_value("a b"), r"a\ b");
+/// let filter = format!("@source:{{{}}}", escape_tag_value("web:v2"));
+/// ```
+pub fn escape_tag_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if (c.is_ascii_punctuation() && c != '_') || c.is_whitespace() {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
 pub fn get_uuid(vector: &[f64]) -> String {
     use uuid::Uuid;
     let vector_str = format!("{:?}", vector);
@@ -478,3 +2969,11 @@ pub fn serialize_vector(vector: &[f64]) -> Vec<u8> {
 pub fn deserialize_vector(bytes: &[u8]) -> Vec<f64> {
     RedisEngine::deserialize_vector(bytes)
 }
+
+pub fn serialize_vector_with(vector: &[f64], endianness: Endianness) -> Vec<u8> {
+    RedisEngine::serialize_vector_with(vector, endianness)
+}
+
+pub fn deserialize_vector_with(bytes: &[u8], endianness: Endianness) -> Vec<f64> {
+    RedisEngine::deserialize_vector_with(bytes, endianness)
+}
@@ -1,13 +1,20 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::future::Future;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use async_trait::async_trait;
+use tokio::sync::OnceCell;
+use futures::future;
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
 
 use crate::{
-    RedisConfig, PointStruct, Payload, Metadata,
-    create_collection, get_collection, get_vector,
-    add_vector_and_metadata, delete_vector_and_metadata,
-    VectorStoreError,
-    redis_engine::RedisEngine,
+    RedisConfig, CollectionConfig, ContentLengthPolicy, DimensionPolicy, DistanceMetric, FieldType, IdStrategy, PointStruct, Payload, Metadata,
+    VectorStoreError, QueryOptions, Filter, MetricsObserver, NoopMetrics,
+    redis_engine::{DeletionPlan, IndexStats, KnnCursor, RedisEngine},
+    distance::normalize,
+    google_embedding_driver::GoogleEmbeddingDriver,
+    rerank::{mmr, MMR_CANDIDATE_FACTOR},
 };
 
 /// A search result entry containing the vector ID, similarity score, and associated metadata.
@@ -17,10 +24,20 @@ pub struct Entry {
     pub id: String,
     /// The vector data. Empty unless `include_vectors` was requested in the query.
     pub vector: Vec<f64>,
-    /// Similarity score from the search. Lower = more similar when using COSINE distance.
+    /// Raw distance reported by the search (lower = more similar); `0.0` for entries
+    /// loaded by ID. Use [`similarity`](Self::similarity) for a higher-is-better score.
     pub score: f64,
     /// Arbitrary JSON metadata associated with this vector.
     pub meta: serde_json::Value,
+    /// Metric `score` was measured with.
+    #[serde(default)]
+    pub metric: DistanceMetric,
+    /// When the vector was first stored (unix millis), if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<u64>,
+    /// When the vector was last written (unix millis), if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<u64>,
 }
 
 impl Entry {
@@ -30,8 +47,90 @@ impl Entry {
             vector,
             score,
             meta,
+            metric: DistanceMetric::default(),
+            created_at: None,
+            updated_at: None,
         }
     }
+
+    /// Similarity derived from the raw distance, where higher is better.
+    /// For cosine collections this is `1.0 - score`, the cosine similarity.
+    pub fn similarity(&self) -> f64 {
+        self.metric.similarity(self.score)
+    }
+
+    /// A multi-line description for debugging: the [`Display`](fmt::Display) line, the vector's
+    /// length when loaded, and the metadata as indented JSON.
+    pub fn to_pretty_string(&self) -> String {
+        let mut pretty = self.to_string();
+        if !self.vector.is_empty() {
+            pretty.push_str(&format!("\nvector: {} dims", self.vector.len()));
+        }
+        let meta = serde_json::to_string_pretty(&self.meta).unwrap_or_else(|_| self.meta.to_string());
+        pretty.push_str(&format!("\nmeta: {}", meta));
+        pretty
+    }
+}
+
+/// Characters of content (or metadata) shown by `Entry`'s [`Display`](fmt::Display).
+const ENTRY_SUMMARY_LEN: usize = 80;
+
+/// One line: ID, distance and a truncated summary of the content, or of the metadata for
+/// entries without content (e.g. loaded with `return_fields`).
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let summary = match self.meta.get("content").and_then(|c| c.as_str()) {
+            Some(content) => format!("{:?}", truncate_chars(content, ENTRY_SUMMARY_LEN)),
+            None => truncate_chars(&self.meta.to_string(), ENTRY_SUMMARY_LEN),
+        };
+        write!(f, "{} (score {:.6}): {}", self.id, self.score, summary)
+    }
+}
+
+fn truncate_chars(text: &str, max: usize) -> String {
+    match text.char_indices().nth(max) {
+        Some((end, _)) => format!("{}…", &text[..end]),
+        None => text.to_string(),
+    }
+}
+
+/// Result of [`VectorStoreDriver::upsert_vector_detailed`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpsertOutcome {
+    /// The vector's ID (auto-generated if not provided).
+    pub id: String,
+    /// `true` if the vector was newly inserted, `false` if it replaced an existing one.
+    pub created: bool,
+    /// `true` if nothing was written because [`DimensionPolicy::Skip`] left the vector out.
+    #[serde(default)]
+    pub skipped: bool,
+}
+
+/// A stored point as an entry with score `0.0`; `meta` is the serialized payload.
+impl From<PointStruct> for Entry {
+    fn from(point: PointStruct) -> Self {
+        // A Payload always serializes: every map key is a string
+        let meta = serde_json::to_value(&point.payload).unwrap_or_default();
+        Self {
+            created_at: point.created_at,
+            updated_at: point.updated_at,
+            ..Entry::new(&point.id, point.vector, 0.0, meta)
+        }
+    }
+}
+
+/// Rebuild a point from an entry whose `meta` holds a serialized [`Payload`]. The score is dropped.
+impl TryFrom<Entry> for PointStruct {
+    type Error = VectorStoreError;
+
+    fn try_from(entry: Entry) -> Result<Self, Self::Error> {
+        let payload: Payload = serde_json::from_value(entry.meta)?;
+        Ok(PointStruct {
+            created_at: entry.created_at,
+            updated_at: entry.updated_at,
+            ..PointStruct::new(&entry.id, entry.vector, payload)
+        })
+    }
 }
 
 /// Trait for vector store backends. Implement this to plug in different storage engines.
@@ -51,6 +150,27 @@ pub trait VectorStoreDriver: Send + Sync {
         content: Option<&str>,
     ) -> Result<String, VectorStoreError>;
 
+    /// Like [`upsert_vector`](Self::upsert_vector), also reporting whether the vector was
+    /// newly created or overwrote an existing one.
+    ///
+    /// The default checks [`exists`](Self::exists) before the write, so it is not atomic;
+    /// backends that can tell from the write itself override this.
+    async fn upsert_vector_detailed(
+        &self,
+        vector: Vec<f64>,
+        vector_id: Option<&str>,
+        namespace: Option<&str>,
+        meta: Option<serde_json::Value>,
+        content: Option<&str>,
+    ) -> Result<UpsertOutcome, VectorStoreError> {
+        let existed = match vector_id {
+            Some(id) => self.exists(id).await?,
+            None => false,
+        };
+        let id = self.upsert_vector(vector, vector_id, namespace, meta, content).await?;
+        Ok(UpsertOutcome { id, created: !existed, skipped: false })
+    }
+
     /// Batch-insert or update multiple vectors.
     async fn upsert_vectors_batch(
         &self,
@@ -63,6 +183,8 @@ pub trait VectorStoreDriver: Send + Sync {
 
     /// Search for similar vectors. Provide either a text `query` (embedded via the driver)
     /// or a raw `query_vector`. Returns results sorted by ascending score (most similar first).
+    ///
+    /// Shorthand for [`query_with`](Self::query_with); prefer that for new code.
     async fn query(
         &self,
         query: &str,
@@ -70,20 +192,172 @@ pub trait VectorStoreDriver: Send + Sync {
         include_vectors: bool,
         namespace: Option<&str>,
         query_vector: Option<Vec<f64>>,
-    ) -> Result<Vec<Entry>, VectorStoreError>;
+    ) -> Result<Vec<Entry>, VectorStoreError> {
+        let mut opts = QueryOptions::new()
+            .text(query)
+            .count(count.unwrap_or(10))
+            .include_vectors(include_vectors);
+        if let Some(ns) = namespace {
+            opts = opts.namespace(ns);
+        }
+        if let Some(v) = query_vector {
+            opts = opts.query_vector(v);
+        }
+        self.query_with(opts).await
+    }
+
+    /// Search for similar vectors as described by `opts`.
+    /// Returns results sorted by ascending score (most similar first).
+    ///
+    /// The default fails as unsupported; every searchable backend overrides this.
+    async fn query_with(&self, _opts: QueryOptions) -> Result<Vec<Entry>, VectorStoreError> {
+        Err(unsupported("query_with"))
+    }
+
+    /// Run several vector queries at once, with all searches pipelined into one round-trip.
+    /// Returns one result list per query vector, in input order.
+    ///
+    /// The default runs one [`query_with`](Self::query_with) per vector.
+    async fn query_batch(
+        &self,
+        query_vectors: Vec<Vec<f64>>,
+        count: usize,
+        namespace: Option<&str>,
+    ) -> Result<Vec<Vec<Entry>>, VectorStoreError> {
+        let mut results = Vec::with_capacity(query_vectors.len());
+        for query_vector in query_vectors {
+            let mut opts = QueryOptions::new().query_vector(query_vector).count(count);
+            if let Some(ns) = namespace {
+                opts = opts.namespace(ns);
+            }
+            results.push(self.query_with(opts).await?);
+        }
+        Ok(results)
+    }
+
+    /// Return every vector within `radius` of `query_vector`, closest first.
+    ///
+    /// `radius` is a raw distance in the collection's metric (e.g. `0.2` cosine distance, i.e.
+    /// similarity of at least 0.8); each `Entry.score` holds the hit's distance. The default
+    /// fails as unsupported.
+    async fn query_radius(
+        &self,
+        _query_vector: Vec<f64>,
+        _radius: f64,
+        _namespace: Option<&str>,
+        _include_vectors: bool,
+    ) -> Result<Vec<Entry>, VectorStoreError> {
+        Err(unsupported("query_radius"))
+    }
+
+    /// Find the vectors most similar to an already-stored one (e.g. for recommendations).
+    /// The source vector itself is never part of the results.
+    ///
+    /// The default loads the source with [`load_entry_strict`](Self::load_entry_strict) and
+    /// searches for its vector with [`query_with`](Self::query_with).
+    async fn find_similar(
+        &self,
+        vector_id: &str,
+        count: usize,
+        include_vectors: bool,
+        namespace: Option<&str>,
+    ) -> Result<Vec<Entry>, VectorStoreError> {
+        let source = self.load_entry_strict(vector_id, None).await?;
+
+        // Ask for one extra hit since the source is normally its own nearest neighbor
+        let mut opts = QueryOptions::new()
+            .query_vector(source.vector)
+            .count(count + 1)
+            .include_vectors(include_vectors);
+        if let Some(ns) = namespace {
+            opts = opts.namespace(ns);
+        }
+        let mut results = self.query_with(opts).await?;
+        results.retain(|entry| entry.id != vector_id);
+        results.truncate(count);
+        Ok(results)
+    }
+
+    /// Check whether a vector with this ID is stored, without loading it.
+    ///
+    /// The default loads the entry with [`load_entry`](Self::load_entry); backends with a
+    /// cheaper existence check override this.
+    async fn exists(&self, vector_id: &str) -> Result<bool, VectorStoreError> {
+        Ok(self.load_entry(vector_id, None).await?.is_some())
+    }
+
+    /// Total number of vectors in the collection. Soft-deleted vectors are not counted.
+    ///
+    /// The default fails as unsupported.
+    async fn count(&self) -> Result<usize, VectorStoreError> {
+        Err(unsupported("count"))
+    }
 
     /// Load a single entry by ID.
     async fn load_entry(&self, vector_id: &str, namespace: Option<&str>) -> Result<Option<Entry>, VectorStoreError>;
 
+    /// Like [`load_entry`](Self::load_entry), but a missing entry is a
+    /// [`VectorStoreError::NotFound`] instead of `Ok(None)`.
+    async fn load_entry_strict(&self, vector_id: &str, namespace: Option<&str>) -> Result<Entry, VectorStoreError> {
+        self.load_entry(vector_id, namespace).await?
+            .ok_or_else(|| VectorStoreError::NotFound { kind: "vector", id: vector_id.to_string() })
+    }
+
     /// Load multiple entries by ID. If `ids` is `None`, scans all entries in the collection.
     async fn load_entries(&self, namespace: Option<&str>, ids: Option<Vec<String>>) -> Result<Vec<Entry>, VectorStoreError>;
 }
 
+/// The error returned by [`VectorStoreDriver`] methods a backend does not implement.
+fn unsupported(method: &str) -> VectorStoreError {
+    VectorStoreError::Other(format!("{} is not supported by this driver", method))
+}
+
 /// Trait for embedding models. Implement this to plug in your own text-to-vector service.
 #[async_trait]
 pub trait EmbeddingDriver: Send + Sync {
     /// Convert a text string into a vector embedding.
     async fn embed_string(&self, text: &str) -> Result<Vec<f64>, VectorStoreError>;
+
+    /// Embed a search query. Models that embed queries and documents differently override
+    /// this; the default is [`embed_string`](Self::embed_string).
+    async fn embed_query(&self, text: &str) -> Result<Vec<f64>, VectorStoreError> {
+        self.embed_string(text).await
+    }
+
+    /// Embed a document for storage. The default is [`embed_string`](Self::embed_string).
+    async fn embed_document(&self, text: &str) -> Result<Vec<f64>, VectorStoreError> {
+        self.embed_string(text).await
+    }
+
+    /// Embed several texts, returning one vector per text in order. Models with a batch
+    /// endpoint override this; the default calls [`embed_string`](Self::embed_string) for each.
+    async fn embed_strings(&self, texts: &[String]) -> Result<Vec<Vec<f64>>, VectorStoreError> {
+        let mut vectors = Vec::with_capacity(texts.len());
+        for text in texts {
+            vectors.push(self.embed_string(text).await?);
+        }
+        Ok(vectors)
+    }
+}
+
+/// Documents embedded and written per batch by
+/// [`import_langchain_jsonl`](RedisStackVectorStoreDriver::import_langchain_jsonl).
+pub const LANGCHAIN_IMPORT_BATCH: usize = 100;
+
+/// Hits fetched per cursor read by [`query_stream`](RedisStackVectorStoreDriver::query_stream).
+pub const QUERY_STREAM_PAGE_SIZE: usize = 100;
+
+/// Metadata field holding the original byte length of content truncated by
+/// [`with_max_content_len`](RedisStackVectorStoreDriver::with_max_content_len).
+pub const ORIGINAL_CONTENT_LEN_FIELD: &str = "original_content_len";
+
+/// State of a [`query_stream`](RedisStackVectorStoreDriver::query_stream) between pages.
+struct QueryCursor {
+    engine: RedisEngine,
+    cursor: KnnCursor,
+    include_vectors: bool,
+    metadata: bool,
+    min_score: Option<f64>,
 }
 
 /// Redis Stack Vector Store Driver.
@@ -93,6 +367,21 @@ pub struct RedisStackVectorStoreDriver {
     redis_config: RedisConfig,
     collection_name: String,
     embedding_driver: Arc<dyn EmbeddingDriver>,
+    dimension_policy: DimensionPolicy,
+    sanitize_non_finite: bool,
+    ensure_collection: bool,
+    auto_initialize: Option<bool>,
+    id_strategy: IdStrategy,
+    ttl: Option<Duration>,
+    index_wait: Option<Duration>,
+    collection_config: CollectionConfig,
+    normalize_on_upsert: bool,
+    check_modules: bool,
+    dedup_by_content: bool,
+    max_content_len: Option<usize>,
+    content_length_policy: ContentLengthPolicy,
+    metrics: Arc<dyn MetricsObserver>,
+    engine: OnceCell<RedisEngine>,
 }
 
 impl RedisStackVectorStoreDriver {
@@ -108,33 +397,564 @@ impl RedisStackVectorStoreDriver {
             redis_config,
             collection_name: collection_name.to_string(),
             embedding_driver,
+            dimension_policy: DimensionPolicy::default(),
+            sanitize_non_finite: false,
+            ensure_collection: true,
+            auto_initialize: None,
+            id_strategy: IdStrategy::default(),
+            ttl: None,
+            index_wait: None,
+            collection_config: CollectionConfig::default(),
+            normalize_on_upsert: false,
+            check_modules: true,
+            dedup_by_content: false,
+            max_content_len: None,
+            content_length_policy: ContentLengthPolicy::default(),
+            metrics: Arc::new(NoopMetrics),
+            engine: OnceCell::new(),
         }
     }
 
+    /// A driver for `collection_name` configured from the environment: the Redis connection
+    /// from [`RedisConfig::from_env`], and a Google embedding driver for the model named by
+    /// `EMBEDDING_MODEL` (e.g. `models/text-embedding-004`) authenticated with `GOOGLE_API_KEY`.
+    ///
+    /// Fails with an error listing the missing variables when either is unset or empty.
+    pub fn from_env(collection_name: &str) -> Result<Self, VectorStoreError> {
+        Self::from_lookup(collection_name, |name| std::env::var(name).ok())
+    }
+
+    /// Like [`from_env`](Self::from_env), reading the variables through `lookup`
+    /// (see [`RedisConfig::from_lookup`]).
+    pub fn from_lookup(collection_name: &str, lookup: impl Fn(&str) -> Option<String>) -> Result<Self, VectorStoreError> {
+        let [model, api_key] = ["EMBEDDING_MODEL", "GOOGLE_API_KEY"].map(|name| lookup(name).filter(|v| !v.is_empty()));
+        let (Some(model), Some(api_key)) = (model.as_deref(), api_key.as_deref()) else {
+            let missing: Vec<&str> = [("EMBEDDING_MODEL", &model), ("GOOGLE_API_KEY", &api_key)]
+                .into_iter()
+                .filter(|(_, value)| value.is_none())
+                .map(|(name, _)| name)
+                .collect();
+            return Err(VectorStoreError::Other(format!(
+                "Cannot configure the driver from the environment: {} not set",
+                missing.join(" and ")
+            )));
+        };
+        let embedding_driver = GoogleEmbeddingDriver::new(model, Some(api_key));
+        Ok(Self::new(RedisConfig::from_lookup(lookup), collection_name, Arc::new(embedding_driver)))
+    }
+
+    /// Set how upserted vectors of the wrong length are handled (default: `Strict`).
+    /// See [`DimensionPolicy`] for the recall implications of padding and truncation.
+    pub fn with_dimension_policy(mut self, policy: DimensionPolicy) -> Self {
+        self.dimension_policy = policy;
+        self
+    }
+
+    /// Store `NaN` and infinite vector components as `0.0`, with a logged warning, instead of
+    /// rejecting the upsert (default: `false`). See [`RedisEngine::with_sanitize_non_finite`].
+    pub fn with_sanitize_non_finite(mut self, sanitize: bool) -> Self {
+        self.sanitize_non_finite = sanitize;
+        self
+    }
+
+    /// Whether upserts check that the index exists before writing (default: `true`).
+    /// Disable for bulk loads into a collection created up front with [`initialize`](Self::initialize).
+    pub fn with_ensure_collection(mut self, ensure: bool) -> Self {
+        self.ensure_collection = ensure;
+        self
+    }
+
+    /// How IDs are generated when `upsert_vector` is called without one
+    /// (default: [`IdStrategy::Uuidv5FromVector`]).
+    pub fn with_id_strategy(mut self, strategy: IdStrategy) -> Self {
+        self.id_strategy = strategy;
+        self
+    }
+
+    /// Expire upserted vectors (and their metadata) after `ttl`, e.g. for session memory.
+    /// Use [`set_ttl`](Self::set_ttl) to change the expiration of a single vector later.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Whether `query` creates the index when it is missing.
+    ///
+    /// By default text queries initialize the collection, while queries that supply a
+    /// precomputed `query_vector` never write. Set `false` for read-only replicas or
+    /// collections created out-of-band; `true` restores initialization for every query.
+    /// Queries that do not initialize fail with `NotFound { kind: "collection" }` when the
+    /// collection is missing, which catches a misspelled collection name early.
+    /// The index is looked up once per driver, not on every query.
+    pub fn with_auto_initialize(mut self, auto_initialize: bool) -> Self {
+        self.auto_initialize = Some(auto_initialize);
+        self
+    }
+
+    /// Make [`initialize`](Self::initialize) wait up to `timeout` for the index to finish
+    /// indexing existing documents, so queries right after startup see every vector.
+    pub fn with_index_wait(mut self, timeout: Duration) -> Self {
+        self.index_wait = Some(timeout);
+        self
+    }
+
+    /// Index settings of the collection (dimension, storage mode); default 768-dim hash storage.
+    /// Must match the settings the collection was created with.
+    pub fn with_collection_config(mut self, config: CollectionConfig) -> Self {
+        self.collection_config = config;
+        self
+    }
+
+    /// Scale upserted vectors to unit length before they are stored (default: `false`).
+    ///
+    /// Cosine indexes don't need this, since cosine distance ignores length. It matters when the
+    /// collection uses an inner-product metric, where unit vectors make IP rank like cosine.
+    /// Query vectors are not touched; pass normalized vectors (see [`crate::normalized`]) when querying.
+    pub fn with_normalize_on_upsert(mut self, normalize: bool) -> Self {
+        self.normalize_on_upsert = normalize;
+        self
+    }
+
+    /// Whether [`initialize`](Self::initialize) first verifies with `MODULE LIST` that the
+    /// RediSearch and RedisJSON modules are loaded (default: `true`), failing with an error
+    /// naming the missing ones. Disable it where `MODULE LIST` is not permitted.
+    pub fn with_module_check(mut self, check: bool) -> Self {
+        self.check_modules = check;
+        self
+    }
+
+    /// Skip re-ingesting documents that are already stored (default: `false`).
+    ///
+    /// Upserts that have content but no explicit ID get the hash of their namespace and content
+    /// as their ID (see [`IdStrategy::ContentHash`]); if a vector with that ID already exists,
+    /// nothing is written and its ID is returned with `created: false`. Retried or repeated
+    /// ingestion then never creates duplicates within a namespace, but changed metadata on a
+    /// re-sent document is not applied either.
+    pub fn with_dedup_by_content(mut self, dedup: bool) -> Self {
+        self.dedup_by_content = dedup;
+        self
+    }
+
+    /// Limit stored content to `max_len` bytes (default: unlimited), so very large documents
+    /// don't bloat Redis memory. Longer content is truncated or rejected according to `policy`;
+    /// truncated points record the original length in their metadata as
+    /// [`ORIGINAL_CONTENT_LEN_FIELD`]. The vector is stored as given, so it still reflects the
+    /// full content.
+    pub fn with_max_content_len(mut self, max_len: usize, policy: ContentLengthPolicy) -> Self {
+        self.max_content_len = Some(max_len);
+        self.content_length_policy = policy;
+        self
+    }
+
+    /// Report the timing of every query and upsert, and every failed one, to `observer`
+    /// (default: [`NoopMetrics`]).
+    pub fn with_metrics_observer(mut self, observer: Arc<dyn MetricsObserver>) -> Self {
+        self.metrics = observer;
+        self
+    }
+
     /// Ensure the RediSearch index exists. Idempotent — safe to call multiple times.
+    /// Fails first with an error naming any missing Redis module unless disabled with
+    /// [`with_module_check`](Self::with_module_check).
+    /// With [`with_index_wait`](Self::with_index_wait), also waits for indexing to complete.
     pub async fn initialize(&self) -> Result<(), VectorStoreError> {
-        create_collection(&self.redis_config, &self.collection_name).await
+        let engine = self.get_engine().await?;
+        if self.check_modules {
+            engine.check_modules().await?;
+        }
+        engine.create_collection().await?;
+        let Some(timeout) = self.index_wait else {
+            return Ok(());
+        };
+        if !engine.wait_for_indexing(timeout).await? {
+            log::warn!("Index {} still indexing after {:?}", self.collection_name, timeout);
+        }
+        Ok(())
     }
 
-    async fn get_engine(&self) -> Result<RedisEngine, VectorStoreError> {
-        RedisEngine::new(&self.redis_config, &self.collection_name).await
+    /// Delete every vector in the collection but keep the index. Returns the number deleted.
+    pub async fn clear(&self) -> Result<usize, VectorStoreError> {
+        self.get_engine().await?.clear().await
     }
-}
 
-#[async_trait]
-impl VectorStoreDriver for RedisStackVectorStoreDriver {
-    async fn delete_vector(&self, vector_id: &str) -> Result<(), VectorStoreError> {
-        delete_vector_and_metadata(&self.redis_config, vector_id, &self.collection_name).await
+    /// Tombstone a vector instead of deleting it (see [`RedisEngine::soft_delete`]); it no
+    /// longer shows up in queries or [`count`](VectorStoreDriver::count) but can still be
+    /// loaded by ID. Requires a collection configured with [`CollectionConfig::soft_delete`].
+    pub async fn soft_delete(&self, vector_id: &str) -> Result<(), VectorStoreError> {
+        self.get_engine().await?.soft_delete(vector_id).await
     }
 
-    async fn upsert_vector(
+    /// Physically delete every soft-deleted vector. Returns the number purged.
+    pub async fn purge_deleted(&self) -> Result<usize, VectorStoreError> {
+        self.get_engine().await?.purge_deleted().await
+    }
+
+    /// Preview [`clear`](Self::clear): the keys it would delete, without deleting them.
+    pub async fn deletion_plan(&self) -> Result<DeletionPlan, VectorStoreError> {
+        self.get_engine().await?.deletion_plan().await
+    }
+
+    /// Fail fast if Redis is unreachable or lacks the RediSearch/RedisJSON modules.
+    pub async fn health_check(&self) -> Result<(), VectorStoreError> {
+        let engine = self.get_engine().await?;
+        engine.ping().await?;
+        engine.check_modules().await
+    }
+
+    /// Memory and indexing statistics for the collection's index. See [`IndexStats`].
+    pub async fn index_stats(&self) -> Result<IndexStats, VectorStoreError> {
+        self.get_engine().await?.index_stats().await
+    }
+
+    /// Count the distinct values of an indexed field (e.g. `"source"`), optionally
+    /// restricted by a RediSearch query such as `@namespace:{docs}`.
+    pub async fn distinct_count(&self, field: &str, filter: Option<&str>) -> Result<usize, VectorStoreError> {
+        self.get_engine().await?.distinct_count(field, filter).await
+    }
+
+    /// Add a filterable field to the existing index. See [`RedisEngine::add_index_field`].
+    pub async fn add_index_field(&self, name: &str, field_type: FieldType) -> Result<(), VectorStoreError> {
+        self.get_engine().await?.add_index_field(name, field_type).await
+    }
+
+    /// Count the vectors per value of an indexed field, largest group first. See
+    /// [`RedisEngine::aggregate_count_by`].
+    pub async fn aggregate_count_by(&self, field: &str) -> Result<Vec<(String, usize)>, VectorStoreError> {
+        self.get_engine().await?.aggregate_count_by(field).await
+    }
+
+    /// Embed `content` as a document (see [`EmbeddingDriver::embed_document`]) and upsert it
+    /// with that content. Returns the vector's ID (auto-generated if not provided).
+    pub async fn upsert_text(
+        &self,
+        content: &str,
+        vector_id: Option<&str>,
+        namespace: Option<&str>,
+        meta: Option<serde_json::Value>,
+    ) -> Result<String, VectorStoreError> {
+        let vector = self.embedding_driver.embed_document(content).await?;
+        self.upsert_vector(vector, vector_id, namespace, meta, Some(content)).await
+    }
+
+    /// Import a LangChain JSONL export: one `{page_content, metadata}` document per line,
+    /// converted with [`from_langchain_document`](crate::from_langchain_document).
+    ///
+    /// Documents without an `embedding` are embedded with the driver's embedding model,
+    /// [`LANGCHAIN_IMPORT_BATCH`] texts per [`embed_strings`](EmbeddingDriver::embed_strings)
+    /// call, and every batch is written in one pipeline. Returns the number of documents imported.
+    pub async fn import_langchain_jsonl(&self, path: impl AsRef<std::path::Path>, namespace: Option<&str>) -> Result<usize, VectorStoreError> {
+        let text = tokio::fs::read_to_string(path).await?;
+        let lines: Vec<&str> = text.lines().filter(|line| !line.trim().is_empty()).collect();
+        let engine = self.get_engine().await?;
+
+        let mut imported = 0;
+        for batch in lines.chunks(LANGCHAIN_IMPORT_BATCH) {
+            let mut points = batch
+                .iter()
+                .map(|line| crate::from_langchain_document(serde_json::from_str(line)?))
+                .collect::<Result<Vec<PointStruct>, VectorStoreError>>()?;
+
+            let missing: Vec<usize> = (0..points.len()).filter(|&i| points[i].vector.is_empty()).collect();
+            if !missing.is_empty() {
+                let texts: Vec<String> = missing.iter().map(|&i| points[i].payload.content.clone()).collect();
+                let vectors = self.embedding_driver.embed_strings(&texts).await?;
+                for (i, vector) in missing.into_iter().zip(vectors) {
+                    points[i].vector = vector;
+                }
+            }
+            if self.normalize_on_upsert {
+                points.iter_mut().for_each(|point| normalize(&mut point.vector));
+            }
+            for point in &mut points {
+                self.limit_content(&mut point.payload)?;
+            }
+
+            engine.add_vectors_batch(&points, namespace).await?;
+            imported += points.len();
+        }
+
+        Ok(imported)
+    }
+
+    /// Replace a vector's payload (content and metadata) without re-sending the vector.
+    /// The content is limited by [`with_max_content_len`](Self::with_max_content_len) as on upsert.
+    pub async fn update_metadata<M: Serialize>(&self, vector_id: &str, payload: &Payload<M>) -> Result<(), VectorStoreError> {
+        let engine = self.get_engine().await?;
+        if self.max_content_len.is_none() {
+            return engine.update_metadata(vector_id, payload).await;
+        }
+        let mut payload = serde_json::to_value(payload)?;
+        self.limit_content_json(&mut payload, false)?;
+        let payload: Payload<serde_json::Value> = serde_json::from_value(payload)?;
+        engine.update_metadata(vector_id, &payload).await
+    }
+
+    /// Merge a partial JSON document into a vector's stored payload (requires RedisJSON 2.6+).
+    /// See [`RedisEngine::merge_metadata`]. A string `content` in `patch` is limited by
+    /// [`with_max_content_len`](Self::with_max_content_len) as on upsert.
+    pub async fn merge_metadata(&self, vector_id: &str, mut patch: serde_json::Value) -> Result<(), VectorStoreError> {
+        self.limit_content_json(&mut patch, true)?;
+        self.get_engine().await?.merge_metadata(vector_id, patch).await
+    }
+
+    /// Set (`Some`) or remove (`None`) the expiration of a stored vector and its metadata.
+    pub async fn set_ttl(&self, vector_id: &str, ttl: Option<Duration>) -> Result<(), VectorStoreError> {
+        self.get_engine().await?.set_ttl(vector_id, ttl).await
+    }
+
+    /// List the namespaces that currently hold at least one vector.
+    pub async fn list_namespaces(&self) -> Result<Vec<String>, VectorStoreError> {
+        self.get_engine().await?.list_namespaces().await
+    }
+
+    /// Count the vectors stored in a namespace.
+    pub async fn count_by_namespace(&self, namespace: &str) -> Result<usize, VectorStoreError> {
+        self.get_engine().await?.count_by_namespace(namespace).await
+    }
+
+    /// Stream the whole collection to `writer` as JSON Lines (one `PointStruct` per line).
+    /// Returns the number of points written. Useful for backups and migrations.
+    pub async fn export_entries(&self, writer: impl std::io::Write) -> Result<usize, VectorStoreError> {
+        self.get_engine().await?.export_entries(writer).await
+    }
+
+    /// Walk every entry in the collection, one `SCAN` page (of roughly `batch_size` keys,
+    /// default 100) at a time. Entries are loaded lazily as the stream is polled;
+    /// Redis errors are yielded as `Err` items.
+    pub fn scan_entries(&self, batch_size: Option<usize>) -> impl Stream<Item = Result<Entry, VectorStoreError>> + '_ {
+        let batch_size = batch_size.unwrap_or(100);
+
+        stream::try_unfold((None::<RedisEngine>, 0u64, false), move |(engine, cursor, finished)| async move {
+            if finished {
+                return Ok(None);
+            }
+            let engine = match engine {
+                Some(engine) => engine,
+                None => self.get_engine().await?,
+            };
+
+            let (next_cursor, ids) = engine.scan_ids(cursor, batch_size).await?;
+            let mut entries = Vec::with_capacity(ids.len());
+            for id in ids {
+                if let Some(point) = engine.get_vector(&id).await? {
+                    entries.push(Entry::from(point));
+                }
+            }
+
+            Ok::<_, VectorStoreError>(Some((entries, (Some(engine), next_cursor, next_cursor == 0))))
+        })
+        .map_ok(|entries| stream::iter(entries.into_iter().map(Ok)))
+        .try_flatten()
+        .boxed()
+    }
+
+    /// Like [`query_with`](VectorStoreDriver::query_with), but yields entries as they are
+    /// fetched instead of collecting all `count` of them first: the search runs as a RediSearch
+    /// cursor read [`QUERY_STREAM_PAGE_SIZE`] hits at a time. Dropping the stream early
+    /// releases the cursor.
+    ///
+    /// `mmr` and `return_fields` need the whole result set and are rejected with an error item.
+    /// `metadata: false` skips the payload fetch unless `include_vectors` is also set.
+    /// Not supported on a cluster: the stream yields a single error item there.
+    pub fn query_stream(&self, opts: QueryOptions) -> impl Stream<Item = Result<Entry, VectorStoreError>> + '_ {
+        stream::try_unfold((Some(opts), None::<QueryCursor>), move |(opts, cursor)| async move {
+            let mut cursor = match (opts, cursor) {
+                (Some(opts), _) => self.open_query_cursor(opts).await?,
+                (None, Some(cursor)) => cursor,
+                (None, None) => return Ok(None),
+            };
+            let Some(hits) = cursor.cursor.next_page().await? else { return Ok(None) };
+
+            let entries: Vec<Entry> = if cursor.metadata || cursor.include_vectors {
+                let metric = cursor.engine.distance_metric();
+                let mut entries: Vec<Entry> = cursor
+                    .engine
+                    .get_vectors_batch(&hits, cursor.include_vectors)
+                    .await?
+                    .into_iter()
+                    .filter_map(|(_, score, point)| point.map(|p| Entry { score, metric, ..Entry::from(p) }))
+                    .collect();
+                if !cursor.metadata {
+                    entries.iter_mut().for_each(|entry| entry.meta = serde_json::json!({}));
+                }
+                entries
+            } else {
+                // The cursor page already holds IDs and scores
+                let metric = cursor.engine.distance_metric();
+                hits.into_iter()
+                    .map(|(id, score, _)| Entry { metric, ..Entry::new(&id, Vec::new(), score, serde_json::json!({})) })
+                    .collect()
+            };
+            let entries: Vec<Entry> = entries
+                .into_iter()
+                .filter(|entry| cursor.min_score.is_none_or(|min| entry.similarity() >= min))
+                .collect();
+            Ok::<_, VectorStoreError>(Some((entries, (None, Some(cursor)))))
+        })
+        .map_ok(|entries| stream::iter(entries.into_iter().map(Ok)))
+        .try_flatten()
+        .boxed()
+    }
+
+    /// Hybrid search: the `count` points nearest to `query_vector` among those whose content
+    /// matches `text_query`, as `(@content:(<text_query>))=>[KNN count @vector $vec]`.
+    ///
+    /// `text_query` uses RediSearch full-text syntax (words, `"exact phrases"`, `prefix*`,
+    /// `%fuzzy%`, `-negation`) and is sent as is (see [`Filter::Text`]). The collection must be
+    /// created with [`CollectionConfig::index_content`].
+    pub async fn hybrid_query(&self, text_query: &str, query_vector: Vec<f64>, count: usize) -> Result<Vec<Entry>, VectorStoreError> {
+        let opts = QueryOptions::new()
+            .query_vector(query_vector)
+            .count(count)
+            .filter(Filter::text("content", text_query));
+        self.query_with(opts).await
+    }
+
+    /// Search several collections at once, e.g. per-tenant shards of one corpus: one KNN search
+    /// per collection, run concurrently on this driver's connection, merged by similarity and
+    /// cut to the best `count`.
+    ///
+    /// The collections must share this driver's [`CollectionConfig`]. A collection whose search
+    /// fails (e.g. a missing index) is skipped with a logged warning; the call only fails when
+    /// every collection does. Each hit comes with the name of its collection, as entry IDs
+    /// are those within each collection.
+    pub async fn query_multi(&self, collections: &[&str], query_vector: Vec<f64>, count: usize) -> Result<Vec<(String, Entry)>, VectorStoreError> {
+        self.observed("query_multi", self.search_collections(collections, query_vector, count), |metrics, entries, duration| {
+            metrics.on_query(duration, entries.len())
+        })
+        .await
+    }
+
+    async fn search_collections(&self, collections: &[&str], query_vector: Vec<f64>, count: usize) -> Result<Vec<(String, Entry)>, VectorStoreError> {
+        let engine = self.get_engine().await?;
+        let searches = collections.iter().map(|&collection| {
+            let engine = engine.sibling(collection, &self.collection_config);
+            let query_vector = &query_vector;
+            async move {
+                let hits = engine.search_knn_points_in(query_vector, count, &[], false).await?;
+                let metric = engine.distance_metric();
+                Ok::<Vec<Entry>, VectorStoreError>(
+                    hits.into_iter()
+                        .filter_map(|(_, score, point)| point.map(|p| Entry { score, metric, ..Entry::from(p) }))
+                        .collect(),
+                )
+            }
+        });
+
+        let mut entries = Vec::new();
+        let mut failures = Vec::new();
+        for (collection, result) in collections.iter().zip(future::join_all(searches).await) {
+            match result {
+                Ok(hits) => entries.extend(hits.into_iter().map(|entry| (collection.to_string(), entry))),
+                Err(err) => {
+                    log::warn!("Skipping collection {} in multi-collection query: {}", collection, err);
+                    failures.push(err);
+                }
+            }
+        }
+        if !collections.is_empty() && failures.len() == collections.len() {
+            return Err(failures.swap_remove(0));
+        }
+
+        entries.sort_by(|(_, a), (_, b)| b.similarity().total_cmp(&a.similarity()));
+        entries.truncate(count);
+        Ok(entries)
+    }
+
+    /// [`query_radius`](VectorStoreDriver::query_radius) restricted to the points matching
+    /// `filter`, e.g. `Filter::tag("namespace", ["a", "b"])` for several namespaces.
+    pub async fn query_radius_filtered(
+        &self,
+        query_vector: Vec<f64>,
+        radius: f64,
+        filter: &Filter,
+        include_vectors: bool,
+    ) -> Result<Vec<Entry>, VectorStoreError> {
+        self.radius_entries(query_vector, radius, Some(filter), include_vectors).await
+    }
+
+    async fn radius_entries(
+        &self,
+        query_vector: Vec<f64>,
+        radius: f64,
+        filter: Option<&Filter>,
+        include_vectors: bool,
+    ) -> Result<Vec<Entry>, VectorStoreError> {
+        let search = async {
+            let engine = self.get_engine().await?.with_raw_filter(filter.map(Filter::to_query_string));
+            let hits = engine.search_range(&query_vector, radius, None).await?;
+            let batch = engine.get_vectors_batch(&hits, include_vectors).await?;
+            let metric = engine.distance_metric();
+
+            Ok(batch
+                .into_iter()
+                .filter_map(|(_, score, point)| point.map(|p| Entry { score, metric, ..Entry::from(p) }))
+                .collect())
+        };
+        self.observed("query_radius", search, |metrics, entries: &Vec<Entry>, duration| {
+            metrics.on_query(duration, entries.len())
+        })
+        .await
+    }
+
+    async fn open_query_cursor(&self, opts: QueryOptions) -> Result<QueryCursor, VectorStoreError> {
+        if opts.mmr.is_some() || !opts.return_fields.is_empty() {
+            return Err(VectorStoreError::Other(
+                "query_stream does not support mmr or return_fields; use query_with".to_string(),
+            ));
+        }
+
+        let mut engine = self.get_engine().await?;
+        if let Some(dialect) = opts.dialect {
+            engine = engine.with_dialect(dialect);
+        }
+        if opts.timeout.is_some() {
+            engine = engine.with_query_timeout(opts.timeout).with_on_timeout(opts.on_timeout);
+        }
+        if let Some(field) = &opts.vector_field {
+            engine = engine.with_vector_field(field);
+        }
+        engine = engine.with_raw_filter(opts.pre_filter());
+        if self.auto_initialize.unwrap_or(opts.query_vector.is_none()) {
+            engine.create_collection_once().await?;
+        }
+
+        let vector = match opts.query_vector {
+            Some(v) => v,
+            None => self.embedding_driver.embed_query(&opts.text).await?,
+        };
+        let namespaces: Vec<&str> = opts.namespace.iter().chain(&opts.namespaces).map(String::as_str).collect();
+        let cursor = engine
+            .search_knn_cursor(&vector, opts.count, &namespaces, QUERY_STREAM_PAGE_SIZE)
+            .await?;
+
+        Ok(QueryCursor {
+            engine,
+            cursor,
+            include_vectors: opts.include_vectors,
+            metadata: opts.metadata,
+            min_score: opts.min_score,
+        })
+    }
+
+    /// Close the driver's Redis connection. Dropping the driver is equivalent.
+    pub fn close(self) {
+        drop(self);
+    }
+
+    async fn store_vector(
         &self,
         vector: Vec<f64>,
         vector_id: Option<&str>,
         namespace: Option<&str>,
         meta: Option<serde_json::Value>,
         content: Option<&str>,
-    ) -> Result<String, VectorStoreError> {
+    ) -> Result<UpsertOutcome, VectorStoreError> {
+        let mut vector = vector;
+        if self.normalize_on_upsert {
+            normalize(&mut vector);
+        }
+
         let mut metadata_map = match meta {
             Some(serde_json::Value::Object(map)) => {
                 let mut result = std::collections::HashMap::new();
@@ -160,18 +980,237 @@ impl VectorStoreDriver for RedisStackVectorStoreDriver {
         let mut metadata = Metadata::new(&uri, chunk_id, &source);
         metadata.extra = metadata_map;
 
-        let payload = Payload::new(content_str, metadata);
+        let mut payload = Payload::new(content_str, metadata);
+        self.limit_content(&mut payload)?;
 
+        let engine = self.get_engine().await?;
         let point = match vector_id {
             Some(id) => PointStruct::new(id, vector, payload),
+            None if self.dedup_by_content && content.is_some() => {
+                // The same text in another namespace is another document
+                let id = match namespace {
+                    Some(ns) => IdStrategy::ContentHash.generate(&vector, &format!("{}\0{}", ns, content_str)),
+                    None => IdStrategy::ContentHash.generate(&vector, content_str),
+                };
+                if engine.exists(&id).await? {
+                    log::debug!("Skipping upsert of duplicate content {}", id);
+                    return Ok(UpsertOutcome { id, created: false, skipped: false });
+                }
+                PointStruct::new(&id, vector, payload)
+            }
             None => {
-                let uuid = crate::get_uuid(&vector);
-                PointStruct::new(&uuid, vector, payload)
+                let id = self.id_strategy.generate(&vector, content_str);
+                PointStruct::new(&id, vector, payload)
             }
         };
 
-        let (vid, _) = add_vector_and_metadata(&self.redis_config, &point, &self.collection_name, namespace).await?;
-        Ok(vid)
+        if engine.skips(&point) {
+            return Ok(UpsertOutcome { id: point.id, created: false, skipped: true });
+        }
+        let ((id, _), created) = engine.upsert_point(&point, namespace).await?;
+        Ok(UpsertOutcome { id, created, skipped: false })
+    }
+
+    async fn run_query(&self, opts: QueryOptions) -> Result<Vec<Entry>, VectorStoreError> {
+        // MMR compares candidates by their main `vector`
+        if opts.mmr.is_some() && opts.vector_field.is_some() {
+            return Err(VectorStoreError::Other("mmr cannot be combined with vector_field".to_string()));
+        }
+        let mut engine = self.get_engine().await?;
+        if let Some(dialect) = opts.dialect {
+            engine = engine.with_dialect(dialect);
+        }
+        if opts.timeout.is_some() {
+            engine = engine.with_query_timeout(opts.timeout).with_on_timeout(opts.on_timeout);
+        }
+        if let Some(field) = &opts.vector_field {
+            engine = engine.with_vector_field(field);
+        }
+        engine = engine.with_raw_filter(opts.pre_filter());
+
+        // Read-only by default when the caller already has a vector (e.g. routed to a replica)
+        if self.auto_initialize.unwrap_or(opts.query_vector.is_none()) {
+            engine.create_collection_once().await?;
+        }
+
+        let vector = match opts.query_vector {
+            Some(v) => v,
+            None => self.embedding_driver.embed_query(&opts.text).await?,
+        };
+
+        // MMR needs a larger candidate pool, with vectors, to pick a diverse subset from
+        let (fetch_count, fetch_vectors) = match opts.mmr {
+            Some(_) => (opts.count * MMR_CANDIDATE_FACTOR, true),
+            None => (opts.count, opts.include_vectors),
+        };
+
+        let namespaces: Vec<&str> = opts.namespace.iter().chain(&opts.namespaces).map(String::as_str).collect();
+        let metric = engine.distance_metric();
+        let candidates: Vec<Entry> = if opts.return_fields.is_empty() && opts.metadata {
+            engine
+                .search_knn_points_in(&vector, fetch_count, &namespaces, fetch_vectors)
+                .await?
+                .into_iter()
+                .filter_map(|(_, score, point)| point.map(|p| Entry { score, metric, ..Entry::from(p) }))
+                .collect()
+        } else {
+            // The requested fields (if any) come back in the search reply; no second fetch
+            engine
+                .search_knn_fields_in(&vector, fetch_count, &namespaces, &opts.return_fields, fetch_vectors)
+                .await?
+                .into_iter()
+                .map(|(id, score, vector, fields)| Entry { metric, ..Entry::new(&id, vector, score, fields) })
+                .collect()
+        };
+        let mut entries: Vec<Entry> = candidates
+            .into_iter()
+            .filter(|entry| opts.min_score.is_none_or(|min| entry.similarity() >= min))
+            .collect();
+
+        if let Some(lambda) = opts.mmr {
+            entries = mmr(entries, &vector, lambda, opts.count);
+            if !opts.include_vectors {
+                entries.iter_mut().for_each(|entry| entry.vector.clear());
+            }
+        }
+
+        Ok(entries)
+    }
+
+    async fn run_query_batch(
+        &self,
+        query_vectors: Vec<Vec<f64>>,
+        count: usize,
+        namespace: Option<&str>,
+    ) -> Result<Vec<Vec<Entry>>, VectorStoreError> {
+        let engine = self.get_engine().await?;
+        let per_query = engine.search_knn_batch(&query_vectors, count, namespace).await?;
+
+        // Load the payloads of every hit together, then split them back per query
+        let sizes: Vec<usize> = per_query.iter().map(Vec::len).collect();
+        let hits: Vec<(String, f64, String)> = per_query.into_iter().flatten().collect();
+        let mut batch = engine.get_vectors_batch(&hits, false).await?.into_iter();
+        let metric = engine.distance_metric();
+
+        Ok(sizes
+            .into_iter()
+            .map(|size| {
+                batch
+                    .by_ref()
+                    .take(size)
+                    .filter_map(|(_, score, point)| point.map(|p| Entry { score, metric, ..Entry::from(p) }))
+                    .collect()
+            })
+            .collect())
+    }
+
+    /// Time `operation`, then report it to the metrics observer: through `report` when it
+    /// succeeds, as an error otherwise.
+    async fn observed<T>(
+        &self,
+        operation: &str,
+        fut: impl Future<Output = Result<T, VectorStoreError>>,
+        report: impl FnOnce(&dyn MetricsObserver, &T, Duration),
+    ) -> Result<T, VectorStoreError> {
+        let start = Instant::now();
+        let result = fut.await;
+        match &result {
+            Ok(value) => report(self.metrics.as_ref(), value, start.elapsed()),
+            Err(err) => self.metrics.on_error(operation, err),
+        }
+        result
+    }
+
+    /// The driver's engine, connected on first use. Every call shares one connection.
+    async fn get_engine(&self) -> Result<RedisEngine, VectorStoreError> {
+        let engine = self
+            .engine
+            .get_or_try_init(|| async {
+                Ok::<_, VectorStoreError>(
+                    RedisEngine::with_config(&self.redis_config, &self.collection_name, &self.collection_config)
+                        .await?
+                        .with_dimension_policy(self.dimension_policy)
+                        .with_sanitize_non_finite(self.sanitize_non_finite)
+                        .with_ensure_collection(self.ensure_collection)
+                        .with_ttl(self.ttl),
+                )
+            })
+            .await?;
+        Ok(engine.clone())
+    }
+
+    /// Apply [`with_max_content_len`](Self::with_max_content_len) to a payload about to be stored.
+    fn limit_content(&self, payload: &mut Payload) -> Result<(), VectorStoreError> {
+        if let Some(len) = self.limit_content_len(&mut payload.content)? {
+            payload.metadata.extra.insert(ORIGINAL_CONTENT_LEN_FIELD.to_string(), len.into());
+        }
+        Ok(())
+    }
+
+    /// Like [`limit_content`](Self::limit_content), for the `content` string of a serialized
+    /// payload or (with `patch`) a merge patch, whose new content also clears an earlier
+    /// truncation record.
+    fn limit_content_json(&self, payload: &mut serde_json::Value, patch: bool) -> Result<(), VectorStoreError> {
+        let Some(serde_json::Value::String(content)) = payload.get_mut("content") else {
+            return Ok(());
+        };
+        let original_len = match self.limit_content_len(content)? {
+            Some(len) => serde_json::Value::from(len),
+            None if patch && self.max_content_len.is_some() => serde_json::Value::Null,
+            None => return Ok(()),
+        };
+
+        // Leave `metadata` and `extra` alone if they hold anything but objects
+        let Some(payload) = payload.as_object_mut() else { return Ok(()) };
+        let metadata = payload.entry("metadata").or_insert_with(|| serde_json::json!({}));
+        let Some(metadata) = metadata.as_object_mut() else { return Ok(()) };
+        let extra = metadata.entry("extra").or_insert_with(|| serde_json::json!({}));
+        if let Some(extra) = extra.as_object_mut() {
+            extra.insert(ORIGINAL_CONTENT_LEN_FIELD.to_string(), original_len);
+        }
+        Ok(())
+    }
+
+    /// Cut `content` to [`with_max_content_len`](Self::with_max_content_len), returning the
+    /// original length if it was truncated.
+    fn limit_content_len(&self, content: &mut String) -> Result<Option<usize>, VectorStoreError> {
+        match self.max_content_len {
+            Some(max_len) => self.content_length_policy.apply(content, max_len),
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl VectorStoreDriver for RedisStackVectorStoreDriver {
+    async fn delete_vector(&self, vector_id: &str) -> Result<(), VectorStoreError> {
+        self.get_engine().await?.delete_vector_and_metadata(vector_id).await
+    }
+
+    async fn upsert_vector(
+        &self,
+        vector: Vec<f64>,
+        vector_id: Option<&str>,
+        namespace: Option<&str>,
+        meta: Option<serde_json::Value>,
+        content: Option<&str>,
+    ) -> Result<String, VectorStoreError> {
+        let outcome = self.upsert_vector_detailed(vector, vector_id, namespace, meta, content).await?;
+        Ok(outcome.id)
+    }
+
+    async fn upsert_vector_detailed(
+        &self,
+        vector: Vec<f64>,
+        vector_id: Option<&str>,
+        namespace: Option<&str>,
+        meta: Option<serde_json::Value>,
+        content: Option<&str>,
+    ) -> Result<UpsertOutcome, VectorStoreError> {
+        self.observed("upsert", self.store_vector(vector, vector_id, namespace, meta, content), |metrics, _, duration| {
+            metrics.on_upsert(duration)
+        })
+        .await
     }
 
     async fn upsert_vectors_batch(
@@ -194,64 +1233,53 @@ impl VectorStoreDriver for RedisStackVectorStoreDriver {
         Ok(())
     }
 
-    async fn query(
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(collection = %self.collection_name, count = opts.count, namespace = ?opts.namespace, namespaces = ?opts.namespaces)))]
+    async fn query_with(&self, opts: QueryOptions) -> Result<Vec<Entry>, VectorStoreError> {
+        self.observed("query", self.run_query(opts), |metrics, entries, duration| {
+            metrics.on_query(duration, entries.len())
+        })
+        .await
+    }
+
+    async fn query_batch(
         &self,
-        query: &str,
-        count: Option<usize>,
-        include_vectors: bool,
+        query_vectors: Vec<Vec<f64>>,
+        count: usize,
         namespace: Option<&str>,
-        query_vector: Option<Vec<f64>>,
-    ) -> Result<Vec<Entry>, VectorStoreError> {
-        if get_collection(&self.redis_config, &self.collection_name).await.is_err() {
-            self.initialize().await?;
-        }
-
-        let vector = match query_vector {
-            Some(v) => v,
-            None => self.embedding_driver.embed_string(query).await?,
-        };
-
-        let engine = self.get_engine().await?;
-        let count = count.unwrap_or(10);
+    ) -> Result<Vec<Vec<Entry>>, VectorStoreError> {
+        self.observed("query_batch", self.run_query_batch(query_vectors, count, namespace), |metrics, results, duration| {
+            metrics.on_query(duration, results.iter().map(Vec::len).sum())
+        })
+        .await
+    }
 
-        // Single KNN query returns (id, score, metadata_json_id)
-        let knn_results = engine.search_knn(&vector, count, namespace).await?;
-        // Batch-fetch all metadata in one helper call
-        let batch = engine.get_vectors_batch(&knn_results, include_vectors).await?;
+    async fn query_radius(
+        &self,
+        query_vector: Vec<f64>,
+        radius: f64,
+        namespace: Option<&str>,
+        include_vectors: bool,
+    ) -> Result<Vec<Entry>, VectorStoreError> {
+        let filter = namespace.filter(|ns| !ns.is_empty()).map(|ns| Filter::tag("namespace", [ns]));
+        self.radius_entries(query_vector, radius, filter.as_ref(), include_vectors).await
+    }
 
-        let entries: Vec<Entry> = batch
-            .into_iter()
-            .filter_map(|(id, score, point)| {
-                point.map(|p| {
-                    let meta = serde_json::to_value(&p.payload).unwrap_or_default();
-                    Entry::new(&id, p.vector, score, meta)
-                })
-            })
-            .collect();
+    async fn exists(&self, vector_id: &str) -> Result<bool, VectorStoreError> {
+        self.get_engine().await?.exists(vector_id).await
+    }
 
-        Ok(entries)
+    async fn count(&self) -> Result<usize, VectorStoreError> {
+        self.get_engine().await?.count().await
     }
 
     async fn load_entry(&self, vector_id: &str, _namespace: Option<&str>) -> Result<Option<Entry>, VectorStoreError> {
-        match get_vector(&self.redis_config, vector_id, Some(&self.collection_name)).await {
-            Ok(Some(data)) => {
-                let meta = serde_json::to_value(&data.payload)?;
-                Ok(Some(Entry::new(&data.id, data.vector, 0.0, meta)))
-            },
-            Ok(None) => Ok(None),
-            Err(e) => Err(e),
-        }
+        Ok(self.get_engine().await?.get_vector(vector_id).await?.map(Entry::from))
     }
 
-    async fn load_entries(&self, namespace: Option<&str>, ids: Option<Vec<String>>) -> Result<Vec<Entry>, VectorStoreError> {
+    async fn load_entries(&self, _namespace: Option<&str>, ids: Option<Vec<String>>) -> Result<Vec<Entry>, VectorStoreError> {
         let vector_ids = ids.unwrap_or_default();
-        let mut entries = Vec::with_capacity(vector_ids.len());
-        for id in vector_ids {
-            if let Ok(Some(entry)) = self.load_entry(&id, namespace).await {
-                entries.push(entry);
-            }
-        }
-        Ok(entries)
+        let points = self.get_engine().await?.get_vectors(&vector_ids).await?;
+        Ok(points.into_iter().flatten().map(Entry::from).collect())
     }
 }
 
@@ -0,0 +1,46 @@
+use crate::distance::cosine_similarity;
+use crate::redis_vector_store_driver::Entry;
+
+/// How many candidates MMR considers per requested result.
+pub(crate) const MMR_CANDIDATE_FACTOR: usize = 4;
+
+/// Maximal marginal relevance: greedily pick `k` entries that are similar to `query` but
+/// dissimilar to the entries already picked.
+///
+/// Each step selects the candidate maximizing
+/// `lambda * sim(query, c) - (1 - lambda) * max(sim(c, s) for s in selected)`,
+/// with cosine similarity on the entries' vectors, so `lambda = 1.0` is plain relevance
+/// order and `lambda = 0.0` maximizes diversity. `candidates` must carry their vectors.
+pub(crate) fn mmr(candidates: Vec<Entry>, query: &[f64], lambda: f64, k: usize) -> Vec<Entry> {
+    let lambda = lambda.clamp(0.0, 1.0);
+    let relevance: Vec<f64> = candidates.iter().map(|e| similarity(query, &e.vector)).collect();
+    let mut remaining: Vec<usize> = (0..candidates.len()).collect();
+    let mut selected: Vec<usize> = Vec::with_capacity(k.min(candidates.len()));
+
+    while selected.len() < k && !remaining.is_empty() {
+        let score = |&i: &usize| {
+            let redundancy = selected
+                .iter()
+                .map(|&s| similarity(&candidates[i].vector, &candidates[s].vector))
+                .fold(f64::NEG_INFINITY, f64::max);
+            let redundancy = if selected.is_empty() { 0.0 } else { redundancy };
+            lambda * relevance[i] - (1.0 - lambda) * redundancy
+        };
+        let (pos, _) = remaining
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| score(a).total_cmp(&score(b)))
+            .expect("remaining is not empty");
+        selected.push(remaining.remove(pos));
+    }
+
+    // Take the chosen entries out in selection order
+    let mut slots: Vec<Option<Entry>> = candidates.into_iter().map(Some).collect();
+    selected.into_iter().filter_map(|i| slots[i].take()).collect()
+}
+
+/// Cosine similarity that never yields `NaN`, so it is safe to rank with.
+fn similarity(a: &[f64], b: &[f64]) -> f64 {
+    let sim = cosine_similarity(a, b);
+    if sim.is_nan() { 0.0 } else { sim }
+}
@@ -0,0 +1,220 @@
+//! HTTP interface to the store, for running it as a standalone vector service.
+//!
+//! Enabled with the `server` feature. [`router`] builds an `axum` router with:
+//!
+//! - `POST /collections/{name}/vectors` — upsert a [`PointStruct`] (optional `?namespace=`);
+//!   replies with an [`UpsertOutcome`], `201 Created` for new points
+//! - `POST /collections/{name}/search` — run a [`SearchRequest`]; replies with `[Entry]`
+//! - `GET /vectors/{id}` — load one point as an [`Entry`]
+//! - `DELETE /vectors/{id}` — delete one point
+//!
+//! The `/vectors/{id}` routes take the collection from `?collection=`, or else read `id` as a
+//! full `collection:id` key, the same way [`get_vector`](crate::get_vector) does. Only the
+//! collections passed to [`router`] are served. Errors reply with `{"error": "..."}`: `404` for
+//! missing points and unknown collections, `504` for timed-out searches, `502` when the
+//! embedding service fails, `500` otherwise.
+//!
+//! ```no_run
+//! use redis_vector_store::{get_embedding_driver, server, CollectionConfig, RedisConfig};
+//! use std::collections::HashMap;
+//! use std::sync::Arc;
+//!
+//! # async fn example() -> std::io::Result<()> {
+//! let collections = HashMap::from([("docs".to_string(), CollectionConfig::new())]);
+//! let embedding_driver = Arc::new(get_embedding_driver("models/text-embedding-004", None));
+//! let app = server::router(RedisConfig::from_env(), embedding_driver, collections);
+//! let listener = tokio::net::TcpListener::bind("0.0.0.0:8080").await?;
+//! axum::serve(listener, app).await
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::Deserialize;
+
+use crate::redis_vector_store_driver::{
+    EmbeddingDriver, Entry, RedisStackVectorStoreDriver, UpsertOutcome, VectorStoreDriver,
+};
+use crate::{CollectionConfig, Filter, PointStruct, QueryOptions, RedisConfig, VectorStoreError};
+
+/// Body of `POST /collections/{name}/search`. Give either `text` (embedded by the server's
+/// embedding driver) or a precomputed `vector`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct SearchRequest {
+    pub text: String,
+    pub vector: Option<Vec<f64>>,
+    pub count: Option<usize>,
+    pub namespace: Option<String>,
+    pub namespaces: Vec<String>,
+    pub include_vectors: bool,
+    pub min_score: Option<f64>,
+    pub vector_field: Option<String>,
+    pub filter: Option<Filter>,
+}
+
+impl From<SearchRequest> for QueryOptions {
+    fn from(req: SearchRequest) -> Self {
+        let mut opts = QueryOptions::new().text(&req.text).include_vectors(req.include_vectors);
+        if let Some(vector) = req.vector {
+            opts = opts.query_vector(vector);
+        }
+        if let Some(count) = req.count {
+            opts = opts.count(count);
+        }
+        if let Some(namespace) = &req.namespace {
+            opts = opts.namespace(namespace);
+        }
+        opts.namespaces = req.namespaces;
+        if let Some(min_score) = req.min_score {
+            opts = opts.min_score(min_score);
+        }
+        opts.vector_field = req.vector_field;
+        opts.filter = req.filter;
+        opts
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct NamespaceQuery {
+    namespace: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct CollectionQuery {
+    collection: Option<String>,
+}
+
+/// Shared state: one driver (and connection, opened on first use) per served collection.
+struct AppState {
+    drivers: HashMap<String, Arc<RedisStackVectorStoreDriver>>,
+}
+
+impl AppState {
+    fn driver(&self, collection: &str) -> Result<Arc<RedisStackVectorStoreDriver>, ApiError> {
+        self.drivers.get(collection).cloned().ok_or_else(|| {
+            VectorStoreError::NotFound { kind: "collection", id: collection.to_string() }.into()
+        })
+    }
+
+    /// Resolve `/vectors/{id}` to a driver and an ID within its collection.
+    fn locate(&self, id: &str, query: CollectionQuery) -> Result<(Arc<RedisStackVectorStoreDriver>, String), ApiError> {
+        match (query.collection, id.split_once(':')) {
+            (Some(collection), _) => Ok((self.driver(&collection)?, id.to_string())),
+            (None, Some((collection, id))) => Ok((self.driver(collection)?, id.to_string())),
+            (None, None) => Err(ApiError(
+                StatusCode::BAD_REQUEST,
+                "Pass ?collection= or a collection:id key".to_string(),
+            )),
+        }
+    }
+}
+
+/// Build the HTTP router serving `collections`, each with its [`CollectionConfig`]. Every
+/// collection's driver uses `embedding_driver` for text searches.
+///
+/// Searches never create a collection; upserts create a missing one with its configured schema.
+pub fn router(
+    redis_config: RedisConfig,
+    embedding_driver: Arc<dyn EmbeddingDriver>,
+    collections: HashMap<String, CollectionConfig>,
+) -> Router {
+    let drivers = collections
+        .into_iter()
+        .map(|(name, config)| {
+            let driver = RedisStackVectorStoreDriver::new(redis_config.clone(), &name, Arc::clone(&embedding_driver))
+                .with_collection_config(config)
+                .with_auto_initialize(false);
+            (name, Arc::new(driver))
+        })
+        .collect();
+    let state = Arc::new(AppState { drivers });
+
+    Router::new()
+        .route("/collections/{name}/vectors", post(upsert_vector))
+        .route("/collections/{name}/search", post(search))
+        .route("/vectors/{id}", get(get_vector).delete(delete_vector))
+        .with_state(state)
+}
+
+async fn upsert_vector(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+    Query(query): Query<NamespaceQuery>,
+    Json(point): Json<PointStruct>,
+) -> Result<(StatusCode, Json<UpsertOutcome>), ApiError> {
+    let metadata = point.payload.metadata;
+    // The driver takes flat metadata: the known fields next to the extra ones
+    let mut meta: serde_json::Map<String, serde_json::Value> = metadata.extra.into_iter().collect();
+    meta.insert("uri".to_string(), metadata.uri.into());
+    meta.insert("chunk_id".to_string(), metadata.chunk_id.into());
+    meta.insert("source".to_string(), metadata.source.into());
+
+    let outcome = state
+        .driver(&name)?
+        .upsert_vector_detailed(
+            point.vector,
+            Some(&point.id),
+            query.namespace.as_deref(),
+            Some(serde_json::Value::Object(meta)),
+            Some(&point.payload.content),
+        )
+        .await?;
+    let status = if outcome.created { StatusCode::CREATED } else { StatusCode::OK };
+    Ok((status, Json(outcome)))
+}
+
+async fn search(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+    Json(req): Json<SearchRequest>,
+) -> Result<Json<Vec<Entry>>, ApiError> {
+    let entries = state.driver(&name)?.query_with(req.into()).await?;
+    Ok(Json(entries))
+}
+
+async fn get_vector(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(query): Query<CollectionQuery>,
+) -> Result<Json<Entry>, ApiError> {
+    let (driver, id) = state.locate(&id, query)?;
+    Ok(Json(driver.load_entry_strict(&id, None).await?))
+}
+
+async fn delete_vector(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(query): Query<CollectionQuery>,
+) -> Result<StatusCode, ApiError> {
+    let (driver, id) = state.locate(&id, query)?;
+    driver.delete_vector(&id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// An HTTP error response; [`VectorStoreError`]s map to a status by kind.
+struct ApiError(StatusCode, String);
+
+impl From<VectorStoreError> for ApiError {
+    fn from(err: VectorStoreError) -> Self {
+        let status = match &err {
+            VectorStoreError::NotFound { .. } => StatusCode::NOT_FOUND,
+            VectorStoreError::Timeout(_) => StatusCode::GATEWAY_TIMEOUT,
+            VectorStoreError::HttpError(_) => StatusCode::BAD_GATEWAY,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        ApiError(status, err.to_string())
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.0, Json(serde_json::json!({ "error": self.1 }))).into_response()
+    }
+}
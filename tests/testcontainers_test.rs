@@ -0,0 +1,96 @@
+//! End-to-end tests against a throwaway Redis Stack container.
+//!
+//! Run with `cargo test --features testcontainers` (needs a Docker daemon).
+
+use std::sync::Arc;
+
+use redis_vector_store::redis_vector_store_driver::{
+    get_redis_vector_store_driver, RedisStackVectorStoreDriver, VectorStoreDriver,
+};
+use redis_vector_store::{get_embedding_driver, CollectionConfig, QueryOptions, RedisConfig};
+use testcontainers::core::{IntoContainerPort, WaitFor};
+use testcontainers::runners::AsyncRunner;
+use testcontainers::{ContainerAsync, GenericImage};
+
+const DIM: usize = 4;
+
+/// Start Redis Stack and return the container (stopped on drop) with a config pointing at it.
+async fn start_redis() -> (ContainerAsync<GenericImage>, RedisConfig) {
+    let container = GenericImage::new("redis/redis-stack-server", "latest")
+        .with_exposed_port(6379.tcp())
+        .with_wait_for(WaitFor::message_on_stdout("Ready to accept connections"))
+        .start()
+        .await
+        .expect("start redis-stack container");
+    let host = container.get_host().await.unwrap().to_string();
+    let port = container.get_host_port_ipv4(6379).await.unwrap();
+    (container, RedisConfig::new(&host, port, None))
+}
+
+async fn driver(config: &RedisConfig, name: &str) -> RedisStackVectorStoreDriver {
+    let driver = get_redis_vector_store_driver(config.clone(), name, Arc::new(get_embedding_driver("test", None)))
+        .with_collection_config(CollectionConfig::new().vector_dim(DIM));
+    driver.initialize().await.unwrap();
+    driver
+}
+
+#[tokio::test]
+async fn crud_roundtrip_ranks_nearest_first() {
+    let (_container, config) = start_redis().await;
+    let driver = driver(&config, "tc_crud").await;
+
+    driver.upsert_vector(vec![1.0, 0.0, 0.0, 0.0], Some("x"), None, Some(serde_json::json!({"source": "x.txt"})), Some("x axis")).await.unwrap();
+    driver.upsert_vector(vec![0.0, 1.0, 0.0, 0.0], Some("y"), None, None, Some("y axis")).await.unwrap();
+    driver.upsert_vector(vec![0.9, 0.1, 0.0, 0.0], Some("near_x"), None, None, Some("almost x")).await.unwrap();
+
+    let entry = driver.load_entry("x", None).await.unwrap().expect("stored entry");
+    assert_eq!(entry.meta["content"], "x axis");
+    assert_eq!(entry.meta["metadata"]["source"], "x.txt");
+
+    let hits = driver
+        .query_with(QueryOptions::new().query_vector(vec![1.0, 0.0, 0.0, 0.0]).count(3))
+        .await
+        .unwrap();
+    let ids: Vec<&str> = hits.iter().map(|e| e.id.as_str()).collect();
+    assert_eq!(ids, ["x", "near_x", "y"]);
+    assert!(hits[0].score.abs() < 1e-9, "exact match has distance 0");
+    assert!(hits.windows(2).all(|w| w[0].score <= w[1].score));
+
+    driver.delete_vector("x").await.unwrap();
+    assert!(driver.load_entry("x", None).await.unwrap().is_none());
+    let hits = driver
+        .query_with(QueryOptions::new().query_vector(vec![1.0, 0.0, 0.0, 0.0]).count(3))
+        .await
+        .unwrap();
+    assert_eq!(hits[0].id, "near_x");
+}
+
+#[tokio::test]
+async fn query_on_empty_collection_returns_nothing() {
+    let (_container, config) = start_redis().await;
+    let driver = driver(&config, "tc_empty").await;
+
+    let hits = driver
+        .query_with(QueryOptions::new().query_vector(vec![1.0, 0.0, 0.0, 0.0]).count(5))
+        .await
+        .unwrap();
+    assert!(hits.is_empty());
+}
+
+#[tokio::test]
+async fn namespace_filter_restricts_results() {
+    let (_container, config) = start_redis().await;
+    let driver = driver(&config, "tc_ns").await;
+
+    driver.upsert_vector(vec![1.0, 0.0, 0.0, 0.0], Some("a1"), Some("alpha"), None, None).await.unwrap();
+    driver.upsert_vector(vec![0.9, 0.1, 0.0, 0.0], Some("a2"), Some("alpha"), None, None).await.unwrap();
+    driver.upsert_vector(vec![1.0, 0.0, 0.0, 0.0], Some("b1"), Some("beta"), None, None).await.unwrap();
+
+    let query = QueryOptions::new().query_vector(vec![1.0, 0.0, 0.0, 0.0]).count(10);
+    let alpha = driver.query_with(query.clone().namespace("alpha")).await.unwrap();
+    let ids: Vec<&str> = alpha.iter().map(|e| e.id.as_str()).collect();
+    assert_eq!(ids, ["a1", "a2"]);
+
+    let missing = driver.query_with(query.namespace("gamma")).await.unwrap();
+    assert!(missing.is_empty());
+}